@@ -1,232 +1,147 @@
-use halo2_proofs::{arithmetic::FieldExt, circuit::*, plonk::*, poly::Rotation};
-use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
-use halo2_proofs::transcript::{Blake2bRead, Blake2bWrite, Challenge255, EncodedChallenge};
-use halo2_proofs::plonk::{
-    create_proof, keygen_pk, keygen_vk, verify_proof, Advice, Assigned, BatchVerifier, Circuit,
-    Column, ConstraintSystem, Error, Fixed, SingleVerifier, TableColumn, VerificationStrategy,
-};
-use halo2_proofs::poly::{commitment::Params};
-use halo2_proofs::pasta::{Eq, EqAffine};
+use halo2_proofs::pasta::{EqAffine, Fp};
+use halo2_proofs::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, Error, SingleVerifier};
+use halo2_proofs::poly::commitment::Params;
+use halo2_proofs::transcript::{Blake2bRead, Blake2bWrite, Challenge255};
 use rand_core::OsRng;
 use std::fs::File;
-use std::io::{self, Write, Read, BufReader, BufWriter};
+use std::io::{self, BufReader};
 
-mod wordle;
-use crate::wordle::wordle::{*, utils::*};
+use wordle::wordle::wordle::{*, utils::*};
 
 const K: u32 = 14;
+// Salt the host commits to the final word with. Players learn it together with the word.
+const SALT: u64 = 0x5a17;
+// Day of the puzzle the host is running.
+const DAY: u64 = 1;
 
-fn interpret_diff(diff: Vec<Vec<Fp>>) {
+fn interpret_diff(diff: &[Tile]) {
     let mut diff_str = String::new();
-    for i in 0..5 {
-        if diff[0][i] == Fp::one() {
-            diff_str.push('🟩');
-        } else if diff[1][i] == Fp::one() {
-            diff_str.push('🟨');
-        } else {
-            diff_str.push('🟥');
-        }
+    for tile in diff {
+        diff_str.push(match tile {
+            Tile::Green => '🟩',
+            Tile::Yellow => '🟨',
+            Tile::Gray => '🟥',
+        });
     }
     println!("{}", diff_str);
 }
 
-fn verify_play(final_word: String) {
+fn file_error(path: &str) -> impl Fn(io::Error) -> WordleError + '_ {
+    move |err| WordleError::Io(format!("{}: {}", path, err))
+}
 
-    let final_chars = word_to_chars(&final_word);
+// Reads the public inputs a proof was made for, as written by `prove_play`.
+fn read_public_inputs() -> Result<PublicInputs, WordleError> {
+    let contents = std::fs::read_to_string("public_json.bin").map_err(file_error("public_json.bin"))?;
+    serde_json::from_str(&contents).map_err(|err| WordleError::InvalidInput(err.to_string()))
+}
 
-    let empty_circuit = WordleCircuit::<Fp> {
-        poly_words: [Value::unknown(); WORD_COUNT],
-        word_chars: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_green: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_yellow: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-    };
+// The proof `prove_play` wrote last, read when verifying rather than built in.
+fn read_proof() -> Result<Vec<u8>, WordleError> {
+    std::fs::read("proof.bin").map_err(|err| WordleError::MalformedProof(format!("proof.bin: {}", err)))
+}
 
+fn read_params() -> Result<Params<EqAffine>, WordleError> {
+    let params_fs = File::open("params.bin").map_err(file_error("params.bin"))?;
+    Params::<EqAffine>::read(&mut BufReader::new(params_fs)).map_err(|err| WordleError::MalformedParams(err.to_string()))
+}
 
-    let mut instance = Vec::new();
+// Nullifiers of the proofs accepted so far, across days. There are none before the first.
+fn read_nullifiers() -> Result<Vec<FpRepr>, WordleError> {
+    match std::fs::read_to_string("nullifiers_json.bin") {
+        Ok(contents) => serde_json::from_str(&contents).map_err(|err| WordleError::InvalidInput(format!("nullifiers_json.bin: {}", err))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(file_error("nullifiers_json.bin")(err)),
+    }
+}
 
-    // final word chars
-    let mut final_chars_instance = vec![];
-    for i in 0..WORD_LEN {
-        final_chars_instance.push(Fp::from(final_chars[i]));
+// The registration each player was first accepted with. There are none before the first.
+fn read_registrations() -> Result<Registrations, WordleError> {
+    match std::fs::read_to_string("registrations_json.bin") {
+        Ok(contents) => serde_json::from_str(&contents).map_err(|err| WordleError::InvalidInput(format!("registrations_json.bin: {}", err))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Registrations::default()),
+        Err(err) => Err(file_error("registrations_json.bin")(err)),
     }
-    instance.push(final_chars_instance.clone());
-
-    // read json file to array
-    let mut file = File::open("diffs_json.bin").unwrap();
-    let mut contents = String::new();
-    file.read_to_string(&mut contents).unwrap();
-    let diff_json: Vec<Vec<Vec<u64>>> = serde_json::from_str(&contents).unwrap();
-    let mut diffs = vec![];
-
-    for idx in 0..WORD_COUNT {
-        let mut diff_instance = vec![];
-        for i in 0..2 {
-            let mut col_row = vec![];
-            for j in 0..WORD_LEN {
-                col_row.push(Fp::from(diff_json[idx][i][j]));
-            }
-            diff_instance.push(col_row);
-        }
-        diffs.push(diff_instance.clone());
+}
+
+fn verify_play(schedule: &CommitmentSchedule) -> Result<(), WordleError> {
+    let public_inputs = read_public_inputs()?;
+    let day = public_inputs.day;
+    if schedule.get(day) != Some(public_inputs.commitment) {
+        return Err(WordleError::VerificationFailed(format!("not the puzzle published for day {}", day)));
     }
 
-    println!("Verifying proof for final word {}", final_word);
-    println!("Share Sheet:");
-    for i in 0..WORD_COUNT {
-        interpret_diff(diffs[i].clone());
+    // checked before the nullifier, which a fresh secret would change along with the registration
+    let mut registrations = read_registrations()?;
+    if !registrations.matches(public_inputs.player_tag, public_inputs.registration) {
+        return Err(WordleError::VerificationFailed(format!("player {:?} registered with another secret", public_inputs.player_tag)));
     }
 
-    // color green
-    let mut green = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            green.push(diffs[idx][0][i]);
-        }
+    let mut nullifiers = read_nullifiers()?;
+    if nullifiers.contains(&FpRepr(public_inputs.nullifier)) {
+        return Err(WordleError::VerificationFailed(format!("a result was already submitted with nullifier {:?}", public_inputs.nullifier)));
     }
-    instance.push(green.clone());
 
-    // color yellow
-    let mut yellow = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            yellow.push(diffs[idx][1][i]);
-        }
+    let empty_circuit = WordleCircuit::<Fp>::default();
+
+    println!("Verifying player {:?}'s proof for day {} final word commitment {:?}", public_inputs.player_tag, day, public_inputs.commitment);
+    println!("Share Sheet:");
+    for diff in public_inputs.grid.rows.iter() {
+        interpret_diff(diff);
     }
-    instance.push(yellow.clone());
 
-    let mut instance_slice = [
-        &final_chars_instance.clone()[..],
-        &green.clone()[..],
-        &yellow.clone()[..],
-    ];
+    let instance = public_inputs.to_instance_columns()?;
+    let instance_slice: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
 
-    let params_fs = File::open("params.bin").unwrap();
-    let params = Params::<EqAffine>::read(&mut BufReader::new(params_fs)).unwrap();
+    let params = read_params()?;
 
-    let vk = keygen_vk(&params, &empty_circuit).expect("keygen_vk should not fail");
+    let vk = keygen_vk(&params, &empty_circuit).map_err(|err| WordleError::Proving(format!("{:?}", err)))?;
 
-    // Check that a hardcoded proof is satisfied
-    let proof = include_bytes!("../proof.bin");
+    let proof = read_proof()?;
     let strategy = SingleVerifier::new(&params);
     let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
-    let result = verify_proof(
+    verify_proof(
         &params,
         &vk,
         strategy,
-        &[&instance_slice, &instance_slice],
+        &[&instance_slice[..]],
         &mut transcript,
-    );
-    if result.is_ok() {
-        println!("Proof OK!");
-    } else {
-        println!("Proof not OK!");
-    }
+    )
+    .map_err(|err| match err {
+        // the proof ran out or didn't decode
+        Error::Transcript(err) => WordleError::MalformedProof(err.to_string()),
+        err => WordleError::VerificationFailed(format!("{:?}", err)),
+    })?;
+    println!("Proof OK!");
+
+    registrations.insert(public_inputs.player_tag, public_inputs.registration);
+    let registrations_json = serde_json::to_string(&registrations).map_err(|err| WordleError::InvalidInput(err.to_string()))?;
+    std::fs::write("registrations_json.bin", registrations_json).map_err(file_error("registrations_json.bin"))?;
+
+    nullifiers.push(FpRepr(public_inputs.nullifier));
+    let nullifiers_json = serde_json::to_string(&nullifiers).map_err(|err| WordleError::InvalidInput(err.to_string()))?;
+    std::fs::write("nullifiers_json.bin", nullifiers_json).map_err(file_error("nullifiers_json.bin"))
 }
 
-fn prove_play(words: [String; WORD_COUNT], final_word: String) {    
-    let mut poly_words: [Value<Assigned<Fp>>; WORD_COUNT] = [Value::known(Fp::from(123).into()); WORD_COUNT];
-    let mut word_chars: [[Value<Assigned<Fp>>; WORD_LEN]; WORD_COUNT] = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-
-    for idx in 0..WORD_COUNT {
-        poly_words[idx] = Value::known(Fp::from(word_to_polyhash(&words[idx].clone())).into());
-        let chars = word_to_chars(&words[idx].clone());
-        for i in 0..WORD_LEN {
-            word_chars[idx][i] = Value::known(Fp::from(chars[i]).into());
-        }
-    }
-
-    let final_chars = word_to_chars(&final_word);
-
-    let mut word_diffs_green = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-    let mut word_diffs_yellow = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-    for idx in 0..WORD_COUNT {
-        let chars = word_to_chars(&words[idx].clone());
-        for i in 0..WORD_LEN {
-            word_diffs_green[idx][i] = Value::known((Fp::from(chars[i]) - Fp::from(final_chars[i])).into());
-        }
-
-        for i in 0..WORD_LEN {
-            let yellow_diff = {
-                (0..WORD_LEN).fold(Fp::from(1), |expr, j| {
-                    expr * (Fp::from(chars[i]) - Fp::from(final_chars[j]))
-                })
-            };
-            word_diffs_yellow[idx][i] = Value::known(Fp::from(yellow_diff).into());
-        }
-    }
-
-    let circuit = WordleCircuit::<Fp> {
-        poly_words,
-        word_chars,
-        word_diffs_green,
-        word_diffs_yellow,
-    };
-    let empty_circuit = WordleCircuit::<Fp> {
-        poly_words: [Value::unknown(); WORD_COUNT],
-        word_chars: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_green: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_yellow: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-    };
-
-
-    let mut instance = Vec::new();
-
-    // final word chars
-    let mut final_chars_instance = vec![];
-    for i in 0..WORD_LEN {
-        final_chars_instance.push(Fp::from(final_chars[i]));
-    }
-    instance.push(final_chars_instance.clone());
-
-    let mut diffs = vec![];
-    for idx in 0..WORD_COUNT {
-        diffs.push(compute_diff(&words[idx], &final_word));
-    }
-
-    let mut diffs_u64 = vec![];
-    for idx in 0..WORD_COUNT {
-        diffs_u64.push(compute_diff_u64(&words[idx], &final_word));
-    }
-
-    let diffs_json_str = serde_json::to_string(&diffs_u64).unwrap();
-    let mut diffs_json_file = File::create("diffs_json.bin").unwrap();
-    diffs_json_file.write_all(diffs_json_str.as_bytes()).unwrap();
+fn prove_play(words: Vec<Word>, final_word: Word, salt: Fp, day: u64, hard_mode: bool, player: String, player_secret: Fp) -> Result<(), WordleError> {
+    let play = Play::<WORD_LEN, WORD_COUNT>::new(words, final_word, salt, day, hard_mode, handle_to_field(&player)?, player_secret, Dictionary::default())?;
+    let circuit = play.circuit();
+    let empty_circuit = circuit.without_witnesses();
 
-    // color green
-    let mut green = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            green.push(diffs[idx][0][i]);
-        }
-    }
-    instance.push(green.clone());
+    let public_inputs = play.public_inputs();
+    let instance = public_inputs.to_instance_columns()?;
+    let instance_slice: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
 
-    // color yellow
-    let mut yellow = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            yellow.push(diffs[idx][1][i]);
-        }
-    }
-    instance.push(yellow.clone());
-
-    let mut instance_slice = [
-        &final_chars_instance.clone()[..],
-        &green.clone()[..],
-        &yellow.clone()[..],
-    ];
+    let public_json_str = serde_json::to_string(&public_inputs).map_err(|err| WordleError::InvalidInput(err.to_string()))?;
+    std::fs::write("public_json.bin", public_json_str).map_err(file_error("public_json.bin"))?;
 
     println!("Successfully generated witness");
 
-    let params_vec = std::fs::read(&"params.bin".to_string()).expect("good");
-    // println!("vec {:?}", params_vec);
-
-    // let params_fs = File::open("params.bin").unwrap();
-    // let params = Params::<EqAffine>::read(&mut BufReader::new(params_fs)).unwrap();
-    let params = Params::<EqAffine>::read(&mut BufReader::new(&params_vec[..])).unwrap();
+    let params = read_params()?;
 
-    let vk = keygen_vk(&params, &empty_circuit).expect("keygen_vk should not fail");
-    let pk = keygen_pk(&params, vk.clone(), &empty_circuit).expect("keygen_pk should not fail");
+    let proving = |err: Error| WordleError::Proving(format!("{:?}", err));
+    let vk = keygen_vk(&params, &empty_circuit).map_err(proving)?;
+    let pk = keygen_pk(&params, vk.clone(), &empty_circuit).map_err(proving)?;
     println!("Successfully generated proving key");
 
     let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
@@ -234,64 +149,105 @@ fn prove_play(words: [String; WORD_COUNT], final_word: String) {
     create_proof(
         &params,
         &pk,
-        &[circuit.clone(), circuit.clone()],
-        &[&instance_slice, &instance_slice],
+        &[circuit],
+        &[&instance_slice[..]],
         OsRng,
         &mut transcript,
     )
-    .expect("proof generation should not fail");
+    .map_err(proving)?;
     let proof: Vec<u8> = transcript.finalize();
 
-    std::fs::write("proof.bin", &proof[..])
-        .expect("should succeed to write new proof");
+    std::fs::write("proof.bin", &proof[..]).map_err(file_error("proof.bin"))?;
 
     println!("Successfully wrote proof to proof.bin");
 
-    println!("Verifying proof for final word {}", final_word);
+    println!("Verifying proof for final word {}", play.final_word);
     println!("Share Sheet:");
-    for i in 0..WORD_COUNT {
-        interpret_diff(diffs[i].clone());
+    for word in play.words.iter() {
+        interpret_diff(&compute_diff(word, &play.final_word));
     }
 
     // Check that a hardcoded proof is satisfied
     // let proof = include_bytes!("proof.bin");
     let strategy = SingleVerifier::new(&params);
     let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
-    let result = verify_proof(
+    verify_proof(
         &params,
         &vk,
         strategy,
-        &[&instance_slice, &instance_slice],
+        &[&instance_slice[..]],
         &mut transcript,
-    );
-    if result.is_ok() {
-        println!("Proof OK!");
-    } else {
-        println!("Proof not OK!");
-    }
+    )
+    .map_err(|err| WordleError::VerificationFailed(format!("{:?}", err)))?;
+    println!("Proof OK!");
+    Ok(())
 }
 
-fn write_params() {
-    let mut params_file = File::create("params.bin").unwrap();
+fn write_params() -> Result<(), WordleError> {
+    let mut params_file = File::create("params.bin").map_err(file_error("params.bin"))?;
     let params: Params<EqAffine> = Params::new(K);
-    params.write(&mut params_file).unwrap();
+    params.write(&mut params_file).map_err(file_error("params.bin"))
+}
+
+// A trimmed line of input. Running out of input is an error, not an empty line, so a prompt
+// isn't repeated forever once stdin is closed.
+fn read_line() -> Result<String, WordleError> {
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) => Err(WordleError::Io(String::from("stdin: unexpected end of input"))),
+        Ok(_) => Ok(input.trim().to_string()),
+        Err(err) => Err(file_error("stdin")(err)),
+    }
+}
+
+fn read_player() -> Result<String, WordleError> {
+    println!("Enter your player handle:");
+    read_line()
+}
+
+// Kept by the player; the same secret gives the same nullifier for a day.
+fn read_player_secret() -> Result<Fp, WordleError> {
+    println!("Enter your player secret:");
+    read_line()?
+        .parse::<u64>()
+        .map(Fp::from)
+        .map_err(|err| WordleError::InvalidInput(format!("player secret: {}", err)))
 }
 
-fn play(final_word: String) {
+fn read_hard_mode() -> Result<bool, WordleError> {
+    println!("Hard mode? (y/n)");
+    Ok(read_line()? == "y")
+}
 
+fn play(final_word: Word, salt: Fp, day: u64, hard_mode: bool, player: String, player_secret: Fp) -> Result<(), WordleError> {
     let mut running = true;
     let mut counter = 0;
     let mut words = vec![];
     while running && counter < WORD_COUNT {
         println!("Enter a word:");
-        let mut word = String::new();
-        io::stdin().read_line(&mut word).unwrap();
-        word = word.trim().to_string();
-        assert!(word.len() == 5);
+        let input = read_line()?;
+        let word = match Word::new(&input) {
+            Ok(word) => word,
+            Err(err) => {
+                println!("{}", err);
+                continue;
+            }
+        };
+        if !word.is_in_dictionary() {
+            println!("{}", WordleError::UnknownWord(word.to_string()));
+            continue;
+        }
+        if hard_mode && counter > 0 {
+            let prev_diff = compute_diff(&words[counter - 1], &final_word);
+            if !satisfies_hard_mode(&words[counter - 1], &prev_diff, &word) {
+                println!("Hard mode: revealed hints must be used in subsequent guesses");
+                continue;
+            }
+        }
         words.push(word.clone());
 
         let diff = compute_diff(&words[counter], &final_word);
-        interpret_diff(diff);
+        interpret_diff(&diff);
 
         if word == final_word {
             running = false;
@@ -299,34 +255,42 @@ fn play(final_word: String) {
         counter += 1;
     }
 
-    while counter < 6 {
-        words.push(final_word.clone());
-        counter += 1;
-    }
-
     if !running {
         println!("You win! Generating ZK proof...");
-        prove_play(words.try_into().unwrap(), final_word);
+        prove_play(words, final_word, salt, day, hard_mode, player, player_secret)?;
     } else {
         println!("You lose!");
     }
-    
+    Ok(())
+}
+
+fn run(command: &str) -> Result<(), WordleError> {
+    let final_word = Word::new("fluff")?;
+    let salt = Fp::from(SALT);
+    match command {
+        "play" => {
+            let hard_mode = read_hard_mode()?;
+            let player = read_player()?;
+            let player_secret = read_player_secret()?;
+            play(final_word, salt, DAY, hard_mode, player, player_secret)
+        }
+        "verify" => {
+            let mut schedule = CommitmentSchedule::default();
+            schedule.insert(DAY, compute_commitment(&final_word, salt, DAY));
+            verify_play(&schedule)
+        }
+        "write" => write_params(),
+        _ => {
+            println!("Invalid input");
+            Ok(())
+        }
+    }
 }
 
 fn main() {
-    let final_word = "fluff".to_string();
     println!("Welcome to zk wordle!");
     println!("Enter play to play the game, verify to check a proof, or write to generate a new params file");
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-    input = input.trim().to_string();
-    if input == "play" {
-        play(final_word);
-    } else if input == "verify" {
-        verify_play(final_word);
-    } else if input == "write" {
-        write_params();
-    } else {
-        println!("Invalid input");
+    if let Err(err) = read_line().and_then(|command| run(&command)) {
+        println!("{}", err);
     }
-}
\ No newline at end of file
+}
@@ -1,15 +1,9 @@
-use halo2_proofs::{arithmetic::FieldExt, circuit::*, plonk::*, poly::Rotation};
-use halo2_proofs::{circuit::Value, dev::MockProver, pasta::Fp};
-use halo2_proofs::transcript::{Blake2bRead, Blake2bWrite, Challenge255, EncodedChallenge};
-use halo2_proofs::plonk::{
-    create_proof, keygen_pk, keygen_vk, verify_proof, Advice, Assigned, BatchVerifier, Circuit,
-    Column, ConstraintSystem, Error, Fixed, SingleVerifier, TableColumn, VerificationStrategy,
-};
-use halo2_proofs::poly::{commitment::Params};
-use halo2_proofs::pasta::{Eq, EqAffine};
+use halo2_proofs::pasta::{EqAffine, Fp};
+use halo2_proofs::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, Error, SingleVerifier};
+use halo2_proofs::poly::commitment::Params;
+use halo2_proofs::transcript::{Blake2bRead, Blake2bWrite, Challenge255};
 use rand_core::OsRng;
-use std::fs::File;
-use std::io::{self, Write, Read, BufReader, BufWriter};
+use std::io::BufReader;
 use wasm_bindgen::prelude::*;
 use js_sys::Uint8Array;
 
@@ -17,8 +11,6 @@ use crate::wordle::wordle::{*, utils::*};
 
 pub use wasm_bindgen_rayon::init_thread_pool;
 
-const K: u32 = 14;
-
 extern crate console_error_panic_hook;
 
 #[wasm_bindgen]
@@ -26,201 +18,212 @@ pub fn init_panic_hook() {
     console_error_panic_hook::set_once();
 }
 
-#[wasm_bindgen]
-pub fn verify_play(final_word: String, proof_js: JsValue, diffs_u64_js: JsValue, params_ser: JsValue) -> bool {
+// Errors reach JS as exceptions carrying the message.
+impl From<WordleError> for JsValue {
+    fn from(err: WordleError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+fn from_js<T: serde::de::DeserializeOwned>(value: JsValue, what: &str) -> Result<T, WordleError> {
+    value
+        .into_serde::<T>()
+        .map_err(|err| WordleError::InvalidInput(format!("{}: {}", what, err)))
+}
+
+fn to_js<T: serde::Serialize>(value: &T) -> Result<JsValue, WordleError> {
+    JsValue::from_serde(value).map_err(|err| WordleError::InvalidInput(err.to_string()))
+}
+
+fn fp_from_js(value: JsValue, what: &str) -> Result<Fp, WordleError> {
+    Ok(from_js::<FpRepr>(value, what)?.0)
+}
+
+fn params_from_js(params_ser: JsValue) -> Result<Params<EqAffine>, WordleError> {
     let params_vec = Uint8Array::new(&params_ser).to_vec();
-    let proof = proof_js.into_serde::<Vec<u8>>().unwrap();
-    let diffs_u64 = diffs_u64_js.into_serde::<[[[u64; WORD_LEN]; 2]; WORD_COUNT]>().unwrap();
+    Params::<EqAffine>::read(&mut BufReader::new(&params_vec[..]))
+        .map_err(|err| WordleError::MalformedParams(err.to_string()))
+}
 
-    let final_chars = word_to_chars(&final_word);
+fn proof_from_js(proof_js: JsValue) -> Result<Vec<u8>, WordleError> {
+    proof_js
+        .into_serde::<Vec<u8>>()
+        .map_err(|err| WordleError::MalformedProof(err.to_string()))
+}
 
-    let empty_circuit = WordleCircuit::<Fp> {
-        poly_words: [Value::unknown(); WORD_COUNT],
-        word_chars: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_green: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_yellow: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-    };
+fn words_from_js(words_js: JsValue) -> Result<Vec<Word>, WordleError> {
+    from_js::<Vec<String>>(words_js, "words")?
+        .iter()
+        .map(|word| Word::new(word))
+        .collect()
+}
+
+fn proving(err: Error) -> WordleError {
+    WordleError::Proving(format!("{:?}", err))
+}
 
+#[wasm_bindgen]
+pub fn get_commitment(final_word: String, salt_js: JsValue, day: u32) -> Result<JsValue, JsValue> {
+    let final_word: Word = Word::new(&final_word)?;
+    let salt = fp_from_js(salt_js, "salt")?;
+    Ok(to_js(&FpRepr(compute_commitment(&final_word, salt, day as u64)))?)
+}
 
-    let mut instance = Vec::new();
+#[wasm_bindgen]
+pub fn get_player_tag(player: String) -> Result<JsValue, JsValue> {
+    Ok(to_js(&FpRepr(compute_player_tag(handle_to_field(&player)?)))?)
+}
 
-    // final word chars
-    let mut final_chars_instance = vec![];
-    for i in 0..WORD_LEN {
-        final_chars_instance.push(Fp::from(final_chars[i]));
-    }
-    instance.push(final_chars_instance.clone());
-
-    // read json diffs to array
-    let mut diffs = vec![];
-
-    for idx in 0..WORD_COUNT {
-        let mut diff_instance = vec![];
-        for i in 0..2 {
-            let mut col_row = vec![];
-            for j in 0..WORD_LEN {
-                col_row.push(Fp::from(diffs_u64[idx][i][j]));
-            }
-            diff_instance.push(col_row);
-        }
-        diffs.push(diff_instance.clone());
-    }
+// What the player registers once, see `compute_registration`.
+#[wasm_bindgen]
+pub fn get_registration(player: String, player_secret_js: JsValue) -> Result<JsValue, JsValue> {
+    let player_secret = fp_from_js(player_secret_js, "player secret")?;
+    Ok(to_js(&FpRepr(compute_registration(handle_to_field(&player)?, player_secret)))?)
+}
+
+#[wasm_bindgen]
+pub fn get_nullifier(player_secret_js: JsValue, day: u32) -> Result<JsValue, JsValue> {
+    let player_secret = fp_from_js(player_secret_js, "player secret")?;
+    Ok(to_js(&FpRepr(compute_nullifier(player_secret, day as u64)))?)
+}
 
-    // color green
-    let mut green = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            green.push(diffs[idx][0][i]);
-        }
+// False for a proof whose registration isn't the one `registrations_js` has on file for its
+// player tag. The caller keeps the registrations and the nullifiers already used, records the
+// registration of a player's first accepted proof and turns away a repeated nullifier. Throws
+// on inputs that don't parse rather than returning false.
+#[wasm_bindgen]
+pub fn verify_play(schedule_js: JsValue, registrations_js: JsValue, public_inputs_js: JsValue, proof_js: JsValue, params_ser: JsValue) -> Result<bool, JsValue> {
+    let proof = proof_from_js(proof_js)?;
+    let schedule = from_js::<CommitmentSchedule>(schedule_js, "schedule")?;
+    let registrations = from_js::<Registrations>(registrations_js, "registrations")?;
+    let public_inputs = from_js::<PublicInputs>(public_inputs_js, "public inputs")?;
+    let instance = public_inputs.to_instance_columns()?;
+    if schedule.get(public_inputs.day) != Some(public_inputs.commitment) {
+        return Ok(false);
     }
-    instance.push(green.clone());
-
-    // color yellow
-    let mut yellow = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            yellow.push(diffs[idx][1][i]);
-        }
+    if !registrations.matches(public_inputs.player_tag, public_inputs.registration) {
+        return Ok(false);
     }
-    instance.push(yellow.clone());
 
-    let mut instance_slice = [
-        &final_chars_instance.clone()[..],
-        &green.clone()[..],
-        &yellow.clone()[..],
-    ];
+    let empty_circuit = WordleCircuit::<Fp>::default();
+
+    let instance_slice: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
 
-    // TODO
-    // let params_fs = File::open("params.bin").unwrap();
-    // let params = Params::<EqAffine>::read(&mut BufReader::new(params_fs)).unwrap();
-    let params = Params::<EqAffine>::read(&mut BufReader::new(&params_vec[..])).unwrap();
+    let params = params_from_js(params_ser)?;
 
-    let vk = keygen_vk(&params, &empty_circuit).expect("keygen_vk should not fail");
-    // Check that a hardcoded proof is satisfied
+    let vk = keygen_vk(&params, &empty_circuit).map_err(proving)?;
     let strategy = SingleVerifier::new(&params);
     let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
     
-    verify_proof(
+    Ok(verify_proof(
         &params,
         &vk,
         strategy,
-        &[&instance_slice],
+        &[&instance_slice[..]],
         &mut transcript,
-    ).is_ok()
+    ).is_ok())
 }
 
+// Checks the host's proof that `diff_js`, a row of tiles, is the right feedback for `guess`
+// on `day`.
 #[wasm_bindgen]
-pub fn get_play_diff(final_word: String, words_js: JsValue) -> JsValue {
-    let mut words = words_js.into_serde::<Vec<String>>().unwrap();
-    let mut diffs_u64 = [[[0; WORD_LEN]; 2]; WORD_COUNT];
-    for idx in 0..WORD_COUNT {
-        let diff_u64 = compute_diff_u64(&words[idx], &final_word);
-        for i in 0..2 {
-            for j in 0..WORD_LEN {
-                diffs_u64[idx][i][j] = diff_u64[i][j];
-            }
-        }
-    }
+pub fn verify_feedback(schedule_js: JsValue, day: u32, guess: String, diff_js: JsValue, proof_js: JsValue, params_ser: JsValue) -> Result<bool, JsValue> {
+    let proof = proof_from_js(proof_js)?;
+    let schedule = from_js::<CommitmentSchedule>(schedule_js, "schedule")?;
+    let commitment = match schedule.get(day as u64) {
+        Some(commitment) => commitment,
+        None => return Ok(false),
+    };
 
-    JsValue::from_serde(&diffs_u64).unwrap()
+    let public_inputs = FeedbackPublicInputs::<WORD_LEN> {
+        commitment,
+        day: day as u64,
+        guess: Word::new(&guess)?,
+        tiles: from_js::<Vec<Tile>>(diff_js, "feedback")?,
+    };
+    let instance = public_inputs.to_instance_columns()?;
+    let instance_slice: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
+
+    let params = params_from_js(params_ser)?;
+    let vk = keygen_vk(&params, &FeedbackCircuit::<Fp>::default()).map_err(proving)?;
+
+    let strategy = SingleVerifier::new(&params);
+    let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
+    Ok(verify_proof(
+        &params,
+        &vk,
+        strategy,
+        &[&instance_slice[..]],
+        &mut transcript,
+    ).is_ok())
 }
 
+// The host's side of `verify_feedback`: proves the feedback for `guess` against the solution
+// committed to for `day`, which is what `get_play_diff` gives for it.
 #[wasm_bindgen]
-pub async fn prove_play(final_word: String, words_js: JsValue, params_ser: JsValue) -> JsValue {
-    let mut words = words_js.into_serde::<Vec<String>>().unwrap();
-    let params_vec = Uint8Array::new(&params_ser).to_vec();
+pub async fn prove_feedback(final_word: String, salt_js: JsValue, day: u32, guess: String, params_ser: JsValue) -> Result<JsValue, JsValue> {
+    let final_word: Word = Word::new(&final_word)?;
+    let guess = Word::new(&guess)?;
+    let salt = fp_from_js(salt_js, "salt")?;
 
-    let mut poly_words: [Value<Assigned<Fp>>; WORD_COUNT] = [Value::known(Fp::from(123).into()); WORD_COUNT];
-    let mut word_chars: [[Value<Assigned<Fp>>; WORD_LEN]; WORD_COUNT] = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
+    let circuit = FeedbackCircuit::new(&guess, &final_word, salt);
+    let empty_circuit = circuit.without_witnesses();
 
-    for idx in 0..WORD_COUNT {
-        poly_words[idx] = Value::known(Fp::from(word_to_polyhash(&words[idx].clone())).into());
-        let chars = word_to_chars(&words[idx].clone());
-        for i in 0..WORD_LEN {
-            word_chars[idx][i] = Value::known(Fp::from(chars[i]).into());
-        }
-    }
+    let public_inputs = FeedbackPublicInputs::new(guess, &final_word, salt, day as u64);
+    let instance = public_inputs.to_instance_columns()?;
+    let instance_slice: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
 
-    let final_chars = word_to_chars(&final_word);
-
-    let mut word_diffs_green = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-    let mut word_diffs_yellow = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-    for idx in 0..WORD_COUNT {
-        let chars = word_to_chars(&words[idx].clone());
-        for i in 0..WORD_LEN {
-            word_diffs_green[idx][i] = Value::known((Fp::from(chars[i]) - Fp::from(final_chars[i])).into());
-        }
-
-        for i in 0..WORD_LEN {
-            let yellow_diff = {
-                (0..WORD_LEN).fold(Fp::from(1), |expr, j| {
-                    expr * (Fp::from(chars[i]) - Fp::from(final_chars[j]))
-                })
-            };
-            word_diffs_yellow[idx][i] = Value::known(Fp::from(yellow_diff).into());
-        }
-    }
+    let params = params_from_js(params_ser)?;
 
-    let circuit = WordleCircuit::<Fp> {
-        poly_words,
-        word_chars,
-        word_diffs_green,
-        word_diffs_yellow,
-    };
-    let empty_circuit = WordleCircuit::<Fp> {
-        poly_words: [Value::unknown(); WORD_COUNT],
-        word_chars: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_green: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-        word_diffs_yellow: [[Value::unknown(); WORD_LEN]; WORD_COUNT],
-    };
+    let vk = keygen_vk(&params, &empty_circuit).map_err(proving)?;
+    let pk = keygen_pk(&params, vk, &empty_circuit).map_err(proving)?;
 
+    let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        &[circuit],
+        &[&instance_slice[..]],
+        OsRng,
+        &mut transcript,
+    )
+    .map_err(proving)?;
+    let proof: Vec<u8> = transcript.finalize();
+    Ok(to_js(&proof)?)
+}
 
-    let mut instance = Vec::new();
+// The grid `words_js` gets against `final_word`, as a `Grid`.
+#[wasm_bindgen]
+pub fn get_play_diff(final_word: String, words_js: JsValue) -> Result<JsValue, JsValue> {
+    let final_word = Word::new(&final_word)?;
+    let words = words_from_js(words_js)?;
+    let grid = Grid {
+        rows: words.iter().map(|word| compute_diff(word, &final_word)).collect(),
+    };
 
-    // final word chars
-    let mut final_chars_instance = vec![];
-    for i in 0..WORD_LEN {
-        final_chars_instance.push(Fp::from(final_chars[i]));
-    }
-    instance.push(final_chars_instance.clone());
+    Ok(to_js(&grid)?)
+}
 
-    let mut diffs = vec![];
-    for idx in 0..WORD_COUNT {
-        diffs.push(compute_diff(&words[idx], &final_word));
-    }
+#[wasm_bindgen]
+pub async fn prove_play(final_word: String, salt_js: JsValue, day: u32, hard_mode: bool, player: String, player_secret_js: JsValue, words_js: JsValue, params_ser: JsValue) -> Result<JsValue, JsValue> {
+    let final_word = Word::new(&final_word)?;
+    let words = words_from_js(words_js)?;
+    let salt = fp_from_js(salt_js, "salt")?;
+    let player_secret = fp_from_js(player_secret_js, "player secret")?;
 
-    // color green
-    let mut green = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            green.push(diffs[idx][0][i]);
-        }
-    }
-    instance.push(green.clone());
-
-    // color yellow
-    let mut yellow = vec![];
-    for idx in 0..WORD_COUNT {
-        for i in 0..WORD_LEN {
-            yellow.push(diffs[idx][1][i]);
-        }
-    }
-    instance.push(yellow.clone());
+    let play = Play::<WORD_LEN, WORD_COUNT>::new(words, final_word, salt, day as u64, hard_mode, handle_to_field(&player)?, player_secret, Dictionary::default())?;
+    let circuit = play.circuit();
+    let empty_circuit = circuit.without_witnesses();
 
-    let mut instance_slice = [
-        &final_chars_instance.clone()[..],
-        &green.clone()[..],
-        &yellow.clone()[..],
-    ];
+    let instance = play.public_inputs().to_instance_columns()?;
+    let instance_slice: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
 
     println!("Successfully generated witness");
 
-    // // let params_fs = File::open("params.bin").unwrap();
-    // // let params = Params::<EqAffine>::read(&mut BufReader::new(params_fs)).unwrap();
-    let params = Params::<EqAffine>::read(&mut BufReader::new(&params_vec[..])).unwrap();
+    let params = params_from_js(params_ser)?;
 
-    let vk = keygen_vk(&params, &empty_circuit).expect("keygen_vk should not fail");
-    let pk = keygen_pk(&params, vk.clone(), &empty_circuit).expect("keygen_pk should not fail");
+    let vk = keygen_vk(&params, &empty_circuit).map_err(proving)?;
+    let pk = keygen_pk(&params, vk.clone(), &empty_circuit).map_err(proving)?;
     println!("Successfully generated proving key");
 
     let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
@@ -229,12 +232,11 @@ pub async fn prove_play(final_word: String, words_js: JsValue, params_ser: JsVal
         &params,
         &pk,
         &[circuit.clone()],
-        &[&instance_slice],
+        &[&instance_slice[..]],
         OsRng,
         &mut transcript,
     )
-    .expect("proof generation should not fail");
+    .map_err(proving)?;
     let proof: Vec<u8> = transcript.finalize();
-    // let proof = [1 as u8]; // delete
-    JsValue::from_serde(&proof).unwrap()
-}
\ No newline at end of file
+    Ok(to_js(&proof)?)
+}
@@ -1 +1,11 @@
+// Circuit code walks the letter columns side by side by index, and passes many columns and
+// cells between configure and synthesize, which these lints would have spelled otherwise.
+#![allow(
+    clippy::large_enum_variant,
+    clippy::module_inception,
+    clippy::needless_range_loop,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
+
 pub mod wordle;
\ No newline at end of file
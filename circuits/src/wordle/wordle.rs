@@ -1,18 +1,51 @@
 use halo2_proofs::{
     arithmetic::FieldExt,
-    circuit::{AssignedCell, Layouter, Value},
-    plonk::{Advice, Assigned, Column, ConstraintSystem, Constraints, Error, Expression, Selector, Instance},
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{Advice, Assigned, Column, ConstraintSystem, Error, Expression, Fixed, Selector, Instance},
     poly::Rotation,
 };
+use halo2_gadgets::poseidon::{
+    primitives::{ConstantLength, Domain, P128Pow5T3, Spec},
+    PaddedWord, Pow5Chip, Pow5Config, Sponge,
+};
 use halo2_proofs::{
     circuit::floor_planner::V1,
-    dev::{FailureLocation, MockProver, VerifyFailure},
-    pasta::Fp,
-    plonk::{Any, Circuit},
+    pasta::{pallas, Fp},
+    plonk::Circuit,
 };
 
+mod error;
+pub use error::WordleError;
+
 mod table;
 use table::*;
+pub use table::Dictionary;
+
+mod merkle;
+use merkle::*;
+pub use merkle::{DictionaryTree, MerklePath, DICT_DEPTH};
+
+mod signature;
+use signature::*;
+pub use signature::{key_id, PlayerKey, Signature};
+
+mod disclosure;
+use disclosure::*;
+
+mod word;
+pub use word::Word;
+
+mod tile;
+pub use tile::{Grid, Tile};
+
+mod play;
+use play::word_witness;
+pub use play::Play;
+
+mod public_inputs;
+pub use public_inputs::{
+    DisclosingPublicInputs, FeedbackPublicInputs, MerklePublicInputs, MultiPublicInputs, PrivateGridPublicInputs, PublicInputs,
+};
 
 pub mod utils;
 use utils::*;
@@ -23,120 +56,328 @@ mod is_zero;
 use is_zero::*;
 
 
+// Poseidon over `message` in the `ConstantLength<L>` domain, the same as the primitive. The
+// gadget's own hash takes the padding of an odd L from a fixed column, which only the mock
+// prover reads back, so a real proof fails to synthesize; the padding is absorbed here as
+// advice cells pinned to the constant instead. `zero_column` needs equality enabled.
+fn poseidon_hash<F: FieldExt, const L: usize>(
+    poseidon: Pow5Config<F, 3, 2>,
+    zero_column: Column<Advice>,
+    mut layouter: impl Layouter<F>,
+    message: [AssignedCell<F, F>; L],
+) -> Result<AssignedCell<F, F>, Error>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    let padding = <ConstantLength<L> as Domain<F, 2>>::padding(L)
+        .map(|pad| {
+            layouter.assign_region(
+                || "padding",
+                |mut region| region.assign_advice_from_constant(|| "pad", zero_column, 0, pad),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut sponge = Sponge::<_, _, P128Pow5T3, _, ConstantLength<L>, 3, 2>::new(
+        Pow5Chip::construct(poseidon),
+        layouter.namespace(|| "init"),
+    )?;
+    for (i, word) in message.into_iter().chain(padding).enumerate() {
+        sponge.absorb(layouter.namespace(|| format!("absorb_{}", i)), PaddedWord::Message(word))?;
+    }
+    sponge
+        .finish_absorbing(layouter.namespace(|| "finish absorbing"))?
+        .squeeze(layouter.namespace(|| "squeeze"))
+}
+
 // This helper checks that the value witnessed in a given cell is within a a lookup dictionary table.
 
 #[derive(Debug, Clone)]
 /// A range-constrained value in the circuit produced by the RangeCheckConfig.
 struct RangeConstrained<F: FieldExt>(AssignedCell<Assigned<F>, F>);
 
+/// Cells a word hands on to the next one: whether it was played, the guess count so far,
+/// whether it solved the puzzle, and the hints it revealed for hard mode.
+#[derive(Debug, Clone)]
+pub struct WordState<F: FieldExt> {
+    poly_word: AssignedCell<Assigned<F>, F>,
+    active: AssignedCell<F, F>,
+    count: AssignedCell<F, F>,
+    won: AssignedCell<F, F>,
+    chars: Vec<AssignedCell<F, F>>,
+    greens: Vec<AssignedCell<F, F>>,
+    yellows: Vec<AssignedCell<F, F>>,
+    colors: Vec<AssignedCell<F, F>>,
+}
+
+/// Cells of a synthesized game that circuit variants build on.
+#[derive(Debug, Clone)]
+pub struct GameCells<F: FieldExt> {
+    day: AssignedCell<F, F>,
+    player_id: AssignedCell<F, F>,
+    words: Vec<WordState<F>>,
+}
+
 #[derive(Debug, Clone)]
-pub struct WordCheckConfig<F: FieldExt> {
+pub struct WordCheckConfig<F: FieldExt, const LEN: usize> {
     q_input: Selector,
+    q_guess: Selector,
+    q_answer: Selector,
     q_diff_g: Selector,
     q_diff_y: Selector,
     q_diff_green_is_zero: Selector,
     q_diff_yellow_is_zero: Selector,
-    q_color_is_zero: Selector,
+    q_eq: Selector,
     q_color: Selector,
+    q_active: Selector,
+    q_game_over: Selector,
+    q_hard: Selector,
+    q_pack: Selector,
+    q_mask: Selector,
     poly_word: Column<Advice>,
-    chars: [Column<Advice>; WORD_LEN],
-    color_is_zero_advice_column: [Column<Advice>; WORD_LEN],
-    final_word_chars_instance: Column<Instance>,
-    char_green_instance: Column<Instance>,
-    char_yellow_instance: Column<Instance>,
-    table: DictTableConfig<F>,
-    diffs_green_is_zero: [IsZeroConfig<F>; WORD_LEN],
-    diffs_yellow_is_zero: [IsZeroConfig<F>; WORD_LEN],
-}
-
-// instance rows:   poly    chr0    chr1    chr2    chr3    chr4    q_dict  q_poly  q_color  q_range_check
-// word                                                                                         1
-// final
-// diffs_g
-// diffs_y
-// diffs_g_0
-// diffs_y_0
-// green
-// yellow
-
-impl<F: FieldExt>
-    WordCheckConfig<F>
+    chars: [Column<Advice>; LEN],
+    commitment_instance: Column<Instance>,
+    day_instance: Column<Instance>,
+    guess_count_instance: Column<Instance>,
+    hard_mode_instance: Column<Instance>,
+    char_color_instance: Option<Column<Instance>>,
+    player_tag_instance: Column<Instance>,
+    registration_instance: Column<Instance>,
+    nullifier_instance: Column<Instance>,
+    guess_membership: GuessMembership<F>,
+    answer_table: DictTableConfig<F>,
+    letter_table: DictTableConfig<F>,
+    poseidon: Pow5Config<F, 3, 2>,
+    diffs_green_is_zero: [IsZeroConfig<F>; LEN],
+    diffs_yellow_is_zero: [IsZeroConfig<F>; LEN],
+    eq_is_zero: [IsZeroConfig<F>; LEN],
+    poly_word_is_zero: IsZeroConfig<F>,
+    answer_is_zero: IsZeroConfig<F>,
+    won_is_zero: IsZeroConfig<F>,
+}
+
+/// How guesses are checked against the dictionary: a lookup table fixed in the keys, or a
+/// Merkle path per guess to a dictionary root given as a public input.
+#[derive(Debug, Clone)]
+enum GuessMembership<F: FieldExt> {
+    Table(DictTableConfig<F>),
+    Merkle(MerkleConfig<F>),
+}
+
+// Region layout for one word (chars columns, inverses live in color_is_zero_advice_column):
+//
+// row                      chars[i]                            selectors
+// 0                        guess char                          q_input, q_guess
+// 1                        final char (copied from the final word region)
+// 2                        diff_g = guess - final              q_diff_g, q_diff_green_is_zero
+// 3                        green
+// EQ_FINAL_OFFSET + 3s     guess char i                        (s = 0..LEN)
+//                + 3s + 1  final char (i + s) % LEN
+//                + 3s + 2  guess char i == final char          q_eq
+// EQ_GUESS_OFFSET + 3s     guess char i                        (s = 1..LEN)
+//                + 3s + 1  guess char (i + s) % LEN
+//                + 3s + 2  guess char i == guess char          q_eq
+// YELLOW_OFFSET            diff_y = unmatched copies left      q_diff_y, q_diff_yellow_is_zero
+// YELLOW_OFFSET + 1        yellow
+// COLOR_OFFSET             color = 2 * green + yellow          q_color
+// HARD_OFFSET              previous guess char                 q_hard (not on the first word)
+// HARD_OFFSET + 1          previous green
+// HARD_OFFSET + 2          previous yellow
+// HARD_OFFSET + 3          product of (previous guess char - guess char j) over j < LEN - 2
+//
+// diff_y counts the copies of guess char i in the final word that are neither green nor
+// already claimed by a yellow further left, so a letter is yellow iff it is not green and
+// diff_y is non-zero. Only the combined color (GRAY, YELLOW or GREEN) is public.
+//
+// The poly_word column also carries the active row bookkeeping:
+//
+// row                      poly_word                           selectors
+// 0                        poly word
+// 1                        active                              q_active
+// 2                        previous word's active (1 for the first word)
+// 3                        guess count so far
+// 4                        previous word's guess count (0 for the first word)
+// 5                        won, i.e. every char is green
+// 6                        previous word's won (0 for the first word)
+//
+// A word is active iff the previous word was active and did not win, so the game starts
+// with an active word and stops right after the first solve. An inactive word is all
+// zeros, which forces its colors to GRAY. After the last word, the "game over check"
+// region requires that no further word would have been active, i.e. the game was won.
+//
+// HARD_OFFSET              hard mode flag (public)
+// HARD_OFFSET + 1          hard mode flag * active
+//
+// In hard mode, every green of the previous guess stays in place and every yellow letter of
+// the previous guess appears somewhere in this one. Since the hints carry over from guess to
+// guess, checking consecutive words is enough.
+const GREEN_OFFSET: usize = 3;
+const EQ_FINAL_OFFSET: usize = 4;
+
+impl<F: FieldExt, const LEN: usize>
+    WordCheckConfig<F, LEN>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
 {
+    const EQ_GUESS_OFFSET: usize = EQ_FINAL_OFFSET + 3 * LEN;
+    const YELLOW_OFFSET: usize = Self::EQ_GUESS_OFFSET + 3 * (LEN - 1);
+    const COLOR_OFFSET: usize = Self::YELLOW_OFFSET + 2;
+    const HARD_OFFSET: usize = Self::COLOR_OFFSET + 1;
+
     pub fn configure(meta: &mut ConstraintSystem<F>,
         q_input: Selector,
+        q_guess: Selector,
+        q_answer: Selector,
         q_diff_g: Selector,
         q_diff_y: Selector,
         q_diff_green_is_zero: Selector,
         q_diff_yellow_is_zero: Selector,
-        q_color_is_zero: Selector,
+        q_eq: Selector,
         q_color: Selector,
+        q_active: Selector,
+        q_game_over: Selector,
+        q_hard: Selector,
+        q_pack: Selector,
+        q_mask: Selector,
         poly_word: Column<Advice>,
-        chars: [Column<Advice>; WORD_LEN],
-        color_is_zero_advice_column: [Column<Advice>; WORD_LEN],
-        final_word_chars_instance: Column<Instance>,
-        char_green_instance: Column<Instance>,
-        char_yellow_instance: Column<Instance>,
+        chars: [Column<Advice>; LEN],
+        color_is_zero_advice_column: [Column<Advice>; LEN],
+        commitment_instance: Column<Instance>,
+        day_instance: Column<Instance>,
+        guess_count_instance: Column<Instance>,
+        hard_mode_instance: Column<Instance>,
+        char_color_instance: Option<Column<Instance>>,
+        player_tag_instance: Column<Instance>,
+        registration_instance: Column<Instance>,
+        nullifier_instance: Column<Instance>,
+        poseidon_state: [Column<Advice>; 3],
+        poseidon_partial_sbox: Column<Advice>,
+        poseidon_rc_a: [Column<Fixed>; 3],
+        poseidon_rc_b: [Column<Fixed>; 3],
+        dictionary_root_instance: Option<Column<Instance>>,
     ) -> Self {
-        let table = DictTableConfig::configure(meta);
+        let answer_table = DictTableConfig::configure(meta);
+        let letter_table = DictTableConfig::configure(meta);
+
+        meta.enable_constant(poseidon_rc_b[0]);
+        let poseidon = Pow5Chip::configure::<P128Pow5T3>(
+            meta,
+            poseidon_state,
+            poseidon_partial_sbox,
+            poseidon_rc_a,
+            poseidon_rc_b,
+        );
+
+        let guess_membership = match dictionary_root_instance {
+            Some(root_instance) => GuessMembership::Merkle(MerkleConfig::configure(meta, poseidon_state, poseidon.clone(), root_instance)),
+            None => GuessMembership::Table(DictTableConfig::configure(meta)),
+        };
 
         let mut diffs_green_is_zero = vec![];
         let mut diffs_yellow_is_zero = vec![];
-        for i in 0..WORD_LEN {
+        let mut eq_is_zero = vec![];
+        for i in 0..LEN {
             diffs_green_is_zero.push(IsZeroChip::configure(
                 meta,
                 |meta| meta.query_selector(q_diff_green_is_zero),
-                |meta| meta.query_advice(chars[i], Rotation(-2)),
+                |meta| meta.query_advice(chars[i], Rotation::cur()),
                 color_is_zero_advice_column[i],
             ));
 
             diffs_yellow_is_zero.push(IsZeroChip::configure(
                 meta,
                 |meta| meta.query_selector(q_diff_yellow_is_zero),
-                |meta| meta.query_advice(chars[i], Rotation(-2)),
+                |meta| meta.query_advice(chars[i], Rotation::cur()),
+                color_is_zero_advice_column[i],
+            ));
+
+            eq_is_zero.push(IsZeroChip::configure(
+                meta,
+                |meta| meta.query_selector(q_eq),
+                |meta| meta.query_advice(chars[i], Rotation(-2)) - meta.query_advice(chars[i], Rotation::prev()),
                 color_is_zero_advice_column[i],
             ));
         }
 
-        for i in 0..WORD_LEN {
+        let poly_word_is_zero = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(q_active),
+            |meta| meta.query_advice(poly_word, Rotation::prev()),
+            color_is_zero_advice_column[0],
+        );
+
+        let answer_is_zero = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(q_answer),
+            |meta| meta.query_advice(poly_word, Rotation::cur()),
+            color_is_zero_advice_column[0],
+        );
+
+        let won_is_zero = IsZeroChip::configure(
+            meta,
+            |meta| meta.query_selector(q_active),
+            |meta| {
+                (0..LEN).fold(Expression::Constant(F::from(LEN as u64)), |expr, i| {
+                    expr - meta.query_advice(chars[i], Rotation(GREEN_OFFSET as i32 - 1))
+                })
+            },
+            color_is_zero_advice_column[1],
+        );
+
+        for i in 0..LEN {
             meta.enable_equality(chars[i]);
         }
-        meta.enable_equality(final_word_chars_instance);
-        meta.enable_equality(char_green_instance);
-        meta.enable_equality(char_yellow_instance);
+        meta.enable_equality(poly_word);
+        meta.enable_equality(commitment_instance);
+        meta.enable_equality(day_instance);
+        meta.enable_equality(guess_count_instance);
+        meta.enable_equality(hard_mode_instance);
+        if let Some(char_color_instance) = char_color_instance {
+            meta.enable_equality(char_color_instance);
+        }
+        meta.enable_equality(player_tag_instance);
+        meta.enable_equality(registration_instance);
+        meta.enable_equality(nullifier_instance);
+
+        if let GuessMembership::Table(guess_table) = &guess_membership {
+            meta.lookup(|meta| {
+                let q_lookup = meta.query_selector(q_guess);
+                let poly_word = meta.query_advice(poly_word, Rotation::cur());
+
+                vec![(q_lookup * poly_word, guess_table.value)] // check if q_lookup * value is in the table.
+            });
+        }
 
         meta.lookup(|meta| {
-            let q_lookup = meta.query_selector(q_input);
+            let q_lookup = meta.query_selector(q_answer);
             let poly_word = meta.query_advice(poly_word, Rotation::cur());
 
-            vec![(q_lookup * poly_word, table.value)] // check if q_lookup * value is in the table.
+            vec![(q_lookup * poly_word, answer_table.value)]
         });
 
+        // the answer table has the padding zero like every table, which is not a solution
+        meta.create_gate("answer check", |meta| {
+            let q = meta.query_selector(q_answer);
 
-        meta.create_gate("character range check", |meta| {
-            let q = meta.query_selector(q_input);
-            let mut constraints = vec![];
-            for idx in 0..WORD_LEN {
-                let value = meta.query_advice(chars[idx], Rotation::cur());
-
-                let range_check = |range: usize, value: Expression<F>| {
-                    assert!(range > 0);
-                    (1..range).fold(value.clone(), |expr, i| {
-                        expr * (Expression::Constant(F::from(i as u64)) - value.clone())
-                    })
-                };
+            vec![q * answer_is_zero.expr()]
+        });
 
-                constraints.push(q.clone() * range_check(28, value.clone()));
-            }
+        // character range check
+        for idx in 0..LEN {
+            meta.lookup(|meta| {
+                let q_lookup = meta.query_selector(q_input);
+                let char = meta.query_advice(chars[idx], Rotation::cur());
 
-            constraints
-        });
+                vec![(q_lookup * char, letter_table.value)]
+            });
+        }
 
         meta.create_gate("poly hashing check", |meta| {
             let q = meta.query_selector(q_input);
             let poly_word = meta.query_advice(poly_word, Rotation::cur());
 
             let hash_check = {
-                (0..WORD_LEN).fold(Expression::Constant(F::from(0)), |expr, i| {
+                (0..LEN).fold(Expression::Constant(F::from(0)), |expr, i| {
                     let char = meta.query_advice(chars[i], Rotation::cur());
                     expr * Expression::Constant(F::from(BASE)) + char
                 })
@@ -148,12 +389,22 @@ impl<F: FieldExt>
         meta.create_gate("diff_g checker", |meta| {
             let q = meta.query_selector(q_diff_g);
             let mut constraints = vec![];
-            for i in 0..WORD_LEN {
+            for i in 0..LEN {
                 let char = meta.query_advice(chars[i], Rotation(-2));
                 let final_char = meta.query_advice(chars[i], Rotation(-1));
                 let diff_g = meta.query_advice(chars[i], Rotation::cur());
                 constraints.push(q.clone() * ((char - final_char) - diff_g));
-                // constraints.push(q.clone() * Expression::Constant(F::zero()));
+            }
+
+            constraints
+        });
+
+        meta.create_gate("eq checker", |meta| {
+            let q = meta.query_selector(q_eq);
+            let mut constraints = vec![];
+            for i in 0..LEN {
+                let eq = meta.query_advice(chars[i], Rotation::cur());
+                constraints.push(q.clone() * (eq - eq_is_zero[i].expr()));
             }
 
             constraints
@@ -161,33 +412,60 @@ impl<F: FieldExt>
 
         meta.create_gate("diff_y checker", |meta| {
             let q = meta.query_selector(q_diff_y);
+            let one = Expression::Constant(F::one());
+            let at = |row: usize| Rotation(row as i32 - Self::YELLOW_OFFSET as i32);
             let mut constraints = vec![];
-            for i in 0..WORD_LEN {
-                let char = meta.query_advice(chars[i], Rotation(-3));
+            for i in 0..LEN {
                 let diff_y = meta.query_advice(chars[i], Rotation::cur());
 
-                let yellow_check = {
-                    (0..WORD_LEN).fold(Expression::Constant(F::one()), |expr, i| {
-                        let final_char = meta.query_advice(chars[i], Rotation(-2));
-                        expr * (char.clone() - final_char)
-                    })
-                };
-                constraints.push(q.clone() * (yellow_check - diff_y));
+                // copies of the guess char in the final word that are not already green
+                let available = (0..LEN).fold(Expression::Constant(F::zero()), |expr, s| {
+                    let j = (i + s) % LEN;
+                    let eq = meta.query_advice(chars[i], at(EQ_FINAL_OFFSET + 3 * s + 2));
+                    let green = meta.query_advice(chars[j], at(GREEN_OFFSET));
+                    expr + eq * (one.clone() - green)
+                });
+
+                // copies of the guess char already coloured yellow further left
+                let used = (1..LEN).fold(Expression::Constant(F::zero()), |expr, s| {
+                    let k = (i + s) % LEN;
+                    if k > i {
+                        return expr;
+                    }
+                    let eq = meta.query_advice(chars[i], at(Self::EQ_GUESS_OFFSET + 3 * (s - 1) + 2));
+                    let yellow = meta.query_advice(chars[k], at(Self::YELLOW_OFFSET + 1));
+                    expr + eq * yellow
+                });
+
+                constraints.push(q.clone() * (available - used - diff_y));
             }
 
             constraints
         });
 
-        meta.create_gate("diff_color_is_zero checker", |meta| {
-            let q_green = meta.query_selector(q_diff_green_is_zero);
-            let q_yellow = meta.query_selector(q_diff_yellow_is_zero);
-            let q_color_is_zero = meta.query_selector(q_color_is_zero);
+        // One gate per selector: the mock prover wants every cell a gate queries assigned
+        // wherever any of its selectors is on.
+        meta.create_gate("green check", |meta| {
+            let q = meta.query_selector(q_diff_green_is_zero);
+
             let mut constraints = vec![];
+            for i in 0..LEN {
+                let green = meta.query_advice(chars[i], Rotation::next());
+                constraints.push(q.clone() * (green - diffs_green_is_zero[i].expr()));
+            }
+
+            constraints
+        });
 
-            for i in 0..WORD_LEN {
-                let diff_color_is_zero = meta.query_advice(chars[i], Rotation::cur());
-                constraints.push(q_color_is_zero.clone() * (diff_color_is_zero - (q_green.clone() * diffs_green_is_zero[i].expr() + q_yellow.clone() * diffs_yellow_is_zero[i].expr())));
-                // constraints.push(q_color_is_zero.clone() * diffs_green_is_zero[i].expr() * q_yellow.clone());
+        meta.create_gate("yellow check", |meta| {
+            let q = meta.query_selector(q_diff_yellow_is_zero);
+            let one = Expression::Constant(F::one());
+
+            let mut constraints = vec![];
+            for i in 0..LEN {
+                let green = meta.query_advice(chars[i], Rotation(GREEN_OFFSET as i32 - Self::YELLOW_OFFSET as i32));
+                let yellow = meta.query_advice(chars[i], Rotation::next());
+                constraints.push(q.clone() * (yellow - (one.clone() - green) * (one.clone() - diffs_yellow_is_zero[i].expr())));
             }
 
             constraints
@@ -195,14 +473,107 @@ impl<F: FieldExt>
 
         meta.create_gate("color check", |meta| {
             let q = meta.query_selector(q_color);
-            
+
             let mut constraints = vec![];
-            for i in 0..WORD_LEN {
-                let diff_color = meta.query_advice(chars[i], Rotation(-4));
-                let diff_color_is_zero = meta.query_advice(chars[i], Rotation(-2));
+            for i in 0..LEN {
+                let green = meta.query_advice(chars[i], Rotation(GREEN_OFFSET as i32 - Self::COLOR_OFFSET as i32));
+                let yellow = meta.query_advice(chars[i], Rotation::prev());
                 let color = meta.query_advice(chars[i], Rotation::cur());
-                constraints.push(q.clone() * diff_color * color.clone());
-                constraints.push(q.clone() * diff_color_is_zero * (Expression::Constant(F::one()) - color.clone()));
+                constraints.push(q.clone() * (color - (Expression::Constant(F::from(GREEN)) * green + yellow)));
+            }
+
+            constraints
+        });
+
+        meta.create_gate("active row check", |meta| {
+            let q = meta.query_selector(q_active);
+            let one = Expression::Constant(F::one());
+            let active = meta.query_advice(poly_word, Rotation::cur());
+            let prev_active = meta.query_advice(poly_word, Rotation::next());
+            let count = meta.query_advice(poly_word, Rotation(2));
+            let prev_count = meta.query_advice(poly_word, Rotation(3));
+            let won = meta.query_advice(poly_word, Rotation(4));
+            let prev_won = meta.query_advice(poly_word, Rotation(5));
+
+            let mut constraints = vec![
+                q.clone() * (active.clone() - prev_active * (one.clone() - prev_won)),
+                q.clone() * (won - won_is_zero.expr()),
+                q.clone() * (prev_count + active.clone() - count),
+                // an active word is a real dictionary word, not the padding zero
+                q.clone() * active.clone() * poly_word_is_zero.expr(),
+            ];
+            for i in 0..LEN {
+                let char = meta.query_advice(chars[i], Rotation::prev());
+                constraints.push(q.clone() * (one.clone() - active.clone()) * char);
+            }
+
+            constraints
+        });
+
+        meta.create_gate("game over check", |meta| {
+            let q = meta.query_selector(q_game_over);
+            let active = meta.query_advice(poly_word, Rotation::cur());
+            let won = meta.query_advice(poly_word, Rotation::next());
+
+            [q * active * (Expression::Constant(F::one()) - won)]
+        });
+
+        // Packs the grid into one field element, GUESSES * LEN base 3 digits.
+        meta.create_gate("grid packing check", |meta| {
+            let q = meta.query_selector(q_pack);
+            let acc = meta.query_advice(poly_word, Rotation::cur());
+            let color = meta.query_advice(chars[0], Rotation::cur());
+            let next = meta.query_advice(poly_word, Rotation::next());
+
+            [q * (acc * Expression::Constant(F::from(GREEN + 1)) + color - next)]
+        });
+
+        // A board of a multi-board game sees the shared guess while it's active and zeros once
+        // it's solved, see `assign_mask`.
+        meta.create_gate("board mask check", |meta| {
+            let q = meta.query_selector(q_mask);
+            let active = meta.query_advice(poly_word, Rotation(2));
+
+            let mut constraints = vec![];
+            for column in [poly_word].iter().chain(chars.iter()) {
+                let guess = meta.query_advice(*column, Rotation::cur());
+                let masked = meta.query_advice(*column, Rotation::next());
+                constraints.push(q.clone() * (masked - active.clone() * guess));
+            }
+
+            constraints
+        });
+
+        meta.create_gate("hard mode check", |meta| {
+            let q = meta.query_selector(q_hard);
+            let one = Expression::Constant(F::one());
+            let hard = meta.query_advice(poly_word, Rotation::cur());
+            let enforce = meta.query_advice(poly_word, Rotation::next());
+            let active = meta.query_advice(poly_word, Rotation(1 - Self::HARD_OFFSET as i32));
+
+            let mut constraints = vec![
+                q.clone() * hard.clone() * (one.clone() - hard.clone()),
+                q.clone() * (enforce.clone() - hard * active),
+            ];
+            for i in 0..LEN {
+                let char = meta.query_advice(chars[i], Rotation(-(Self::HARD_OFFSET as i32)));
+                let prev_char = meta.query_advice(chars[i], Rotation::cur());
+                let prev_green = meta.query_advice(chars[i], Rotation::next());
+                let prev_yellow = meta.query_advice(chars[i], Rotation(2));
+                let partial = meta.query_advice(chars[i], Rotation(3));
+
+                constraints.push(q.clone() * enforce.clone() * prev_green * (char - prev_char.clone()));
+
+                // the product is split over two rows to keep the gate degree down
+                let mut missing = vec![];
+                for j in 0..LEN {
+                    let char = meta.query_advice(chars[j], Rotation(-(Self::HARD_OFFSET as i32)));
+                    missing.push(prev_char.clone() - char);
+                }
+                let head = missing[..LEN - 2].iter().fold(one.clone(), |expr, m| expr * m.clone());
+                let tail = missing[LEN - 2..].iter().fold(partial.clone(), |expr, m| expr * m.clone());
+                constraints.push(q.clone() * (partial - head));
+                constraints.push(q.clone() * enforce.clone() * prev_yellow * tail);
             }
 
             constraints
@@ -210,36 +581,132 @@ impl<F: FieldExt>
 
         Self {
             q_input,
+            q_guess,
+            q_answer,
             q_diff_g,
             q_diff_y,
             q_diff_green_is_zero,
             q_diff_yellow_is_zero,
-            q_color_is_zero,
+            q_eq,
             q_color,
+            q_active,
+            q_game_over,
+            q_hard,
+            q_pack,
+            q_mask,
             poly_word,
             chars,
-            color_is_zero_advice_column,
-            final_word_chars_instance,
-            char_green_instance,
-            char_yellow_instance,
-            table,
+            commitment_instance,
+            day_instance,
+            guess_count_instance,
+            hard_mode_instance,
+            char_color_instance,
+            player_tag_instance,
+            registration_instance,
+            nullifier_instance,
+            guess_membership,
+            answer_table,
+            letter_table,
+            poseidon,
             diffs_green_is_zero: diffs_green_is_zero.try_into().unwrap(),
             diffs_yellow_is_zero: diffs_yellow_is_zero.try_into().unwrap(),
+            eq_is_zero: eq_is_zero.try_into().unwrap(),
+            poly_word_is_zero,
+            answer_is_zero,
+            won_is_zero,
+        }
+    }
+
+    pub fn load(&self, layouter: &mut impl Layouter<F>, dictionary: &Dictionary) -> Result<(), Error> {
+        if let GuessMembership::Table(guess_table) = &self.guess_membership {
+            guess_table.load(layouter, &dictionary.guesses)?;
+        }
+        self.answer_table.load(layouter, &dictionary.answers)?;
+        self.letter_table.load(layouter, &(1..=ALPHABET_SIZE).collect::<Vec<_>>())
+    }
+
+    // Lays out `lhs[i] == rhs[i]` for every column i in three rows starting at `offset`. The
+    // inputs are copied in from the cells they were first assigned to.
+    fn assign_eq(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        lhs: &[AssignedCell<F, F>],
+        rhs: &[AssignedCell<F, F>],
+    ) -> Result<Vec<Value<F>>, Error> {
+        self.q_eq.enable(region, offset + 2)?;
+
+        let mut eqs = vec![];
+        for i in 0..LEN {
+            let l = region.assign_advice(|| "eq lhs", self.chars[i], offset, || lhs[i].value().copied())?;
+            region.constrain_equal(l.cell(), lhs[i].cell())?;
+            let r = region.assign_advice(|| "eq rhs", self.chars[i], offset + 1, || rhs[i].value().copied())?;
+            region.constrain_equal(r.cell(), rhs[i].cell())?;
+
+            let diff = lhs[i].value().copied() - rhs[i].value().copied();
+            IsZeroChip::construct(self.eq_is_zero[i].clone()).assign(region, offset + 2, diff)?;
+            let eq = diff.map(|v| if v == F::zero() { F::one() } else { F::zero() });
+            region.assign_advice(|| "eq", self.chars[i], offset + 2, || eq)?;
+            eqs.push(eq);
         }
+
+        Ok(eqs)
+    }
+
+    // The final word is a private witness: it goes through the same range and hashing checks
+    // as a guess but is looked up in the answer list, and only Poseidon(poly word, salt, day)
+    // is made public. Binding the public day into the commitment stops a grid from one day's
+    // puzzle being passed off as another's. `board` is the commitment's row, 0 unless the
+    // game has several boards.
+    pub fn assign_final_word(
+        &self,
+        mut layouter: impl Layouter<F>,
+        poly_word: Value<Assigned<F>>,
+        chars: [Value<Assigned<F>>; LEN],
+        salt: Value<F>,
+        board: usize,
+    ) -> Result<(Vec<AssignedCell<F, F>>, AssignedCell<F, F>), Error> {
+        let (poly_word_cell, salt_cell, day_cell, final_cells) = layouter.assign_region(
+            || "final word checks",
+            |mut region| {
+                self.q_input.enable(&mut region, 0)?;
+                self.q_answer.enable(&mut region, 0)?;
+
+                let poly_word_cell = region.assign_advice(|| "final poly word", self.poly_word, 0, || poly_word.map(|p| p.evaluate()))?;
+                IsZeroChip::construct(self.answer_is_zero.clone()).assign(&mut region, 0, poly_word.map(|p| p.evaluate()))?;
+                let salt_cell = region.assign_advice(|| "salt", self.poly_word, 1, || salt)?;
+                let day_cell = region.assign_advice_from_instance(|| "day", self.day_instance, 0, self.poly_word, 2)?;
+
+                let mut final_cells = vec![];
+                for i in 0..LEN {
+                    final_cells.push(region.assign_advice(|| "final word characters", self.chars[i], 0, || chars[i].map(|c| c.evaluate()))?);
+                }
+
+                Ok((poly_word_cell, salt_cell, day_cell, final_cells))
+            },
+        )?;
+
+        let commitment = self.hash(layouter.namespace(|| "commitment"), [poly_word_cell, salt_cell, day_cell.clone()])?;
+        layouter.constrain_instance(commitment.cell(), self.commitment_instance, board)?;
+
+        Ok((final_cells, day_cell))
     }
 
+    // Chains from the previous word's state (None for the first word) and returns this word's.
+    // The dictionary lookup is left out when the guess was already looked up in `assign_guess`.
     pub fn assign_word(
         &self,
         mut layouter: impl Layouter<F>,
         poly_word: Value<Assigned<F>>,
-        chars: [Value<Assigned<F>>; WORD_LEN],
-        diffs_green: [Value<F>; WORD_LEN],
-        diffs_yellow: [Value<F>; WORD_LEN],
+        chars: [Value<Assigned<F>>; LEN],
+        prev: Option<&WordState<F>>,
+        final_chars: &[AssignedCell<F, F>],
         instance_offset: usize,
-    ) -> Result<(), Error> {
+        lookup_guess: bool,
+    ) -> Result<WordState<F>, Error> {
         let mut diffs_green_is_zero_chips = vec![];
         let mut diffs_yellow_is_zero_chips = vec![];
-        for i in 0..WORD_LEN {
+        for i in 0..LEN {
             diffs_green_is_zero_chips.push(IsZeroChip::construct(self.diffs_green_is_zero[i].clone()));
             diffs_yellow_is_zero_chips.push(IsZeroChip::construct(self.diffs_yellow_is_zero[i].clone()));
         }
@@ -248,236 +715,1484 @@ impl<F: FieldExt>
             || "one word checks",
             |mut region| {
                 self.q_input.enable(&mut region, 0)?;
+                if lookup_guess {
+                    self.q_guess.enable(&mut region, 0)?;
+                }
                 self.q_diff_g.enable(&mut region, 2)?;
-                self.q_diff_y.enable(&mut region, 3)?;
-                self.q_diff_green_is_zero.enable(&mut region, 4)?;
-                self.q_color_is_zero.enable(&mut region, 4)?;
-                self.q_diff_yellow_is_zero.enable(&mut region, 5)?;
-                self.q_color_is_zero.enable(&mut region, 5)?;
-                self.q_color.enable(&mut region, 6)?;
-                self.q_color.enable(&mut region, 7)?;
+                self.q_diff_green_is_zero.enable(&mut region, 2)?;
+                self.q_diff_y.enable(&mut region, Self::YELLOW_OFFSET)?;
+                self.q_diff_yellow_is_zero.enable(&mut region, Self::YELLOW_OFFSET)?;
+                self.q_color.enable(&mut region, Self::COLOR_OFFSET)?;
+                self.q_active.enable(&mut region, 1)?;
 
                 // Assign value
-                region
+                let poly_word_cell = region
                     .assign_advice(|| "poly word", self.poly_word, 0, || poly_word)
                     .map(RangeConstrained)?;
-                
-                for i in 0..WORD_LEN {
-                    region.assign_advice(|| "input word characters", self.chars[i], 0, || chars[i])?;
-                    region.assign_advice_from_instance(|| "final word characters",
-                    self.final_word_chars_instance, i, self.chars[i], 1)?;
-                    region.assign_advice(|| "diff_g", self.chars[i], 2, || diffs_green[i])?;
-                    region.assign_advice(|| "diff_y", self.chars[i], 3, || diffs_yellow[i])?;
-
-                    diffs_green_is_zero_chips[i].assign(&mut region, 4, diffs_green[i])?;
-                    diffs_yellow_is_zero_chips[i].assign(&mut region, 5, diffs_yellow[i])?;
-
-                    let diff_g_is_zero = diffs_green[i].and_then(|v| {
-                        if v == F::zero() {
-                            Value::known(F::one())
-                        } else {
-                            Value::known(F::zero())
+                IsZeroChip::construct(self.poly_word_is_zero.clone()).assign(&mut region, 1, poly_word.map(|p| p.evaluate()))?;
+
+                let (prev_active, prev_count, prev_won) = match prev {
+                    Some(prev) => {
+                        let prev_active = region.assign_advice(|| "prev active", self.poly_word, 2, || prev.active.value().copied())?;
+                        region.constrain_equal(prev_active.cell(), prev.active.cell())?;
+                        let prev_count = region.assign_advice(|| "prev count", self.poly_word, 4, || prev.count.value().copied())?;
+                        region.constrain_equal(prev_count.cell(), prev.count.cell())?;
+                        let prev_won = region.assign_advice(|| "prev won", self.poly_word, 6, || prev.won.value().copied())?;
+                        region.constrain_equal(prev_won.cell(), prev.won.cell())?;
+                        (prev_active, prev_count, prev_won)
+                    }
+                    None => (
+                        region.assign_advice_from_constant(|| "prev active", self.poly_word, 2, F::one())?,
+                        region.assign_advice_from_constant(|| "prev count", self.poly_word, 4, F::zero())?,
+                        region.assign_advice_from_constant(|| "prev won", self.poly_word, 6, F::zero())?,
+                    ),
+                };
+                let active = prev_active.value().copied() * (Value::known(F::one()) - prev_won.value().copied());
+                let active_cell = region.assign_advice(|| "active", self.poly_word, 1, || active)?;
+                let count = prev_count.value().copied() + active;
+                let count_cell = region.assign_advice(|| "count", self.poly_word, 3, || count)?;
+
+                let mut word_cells = vec![];
+                let mut final_cells = vec![];
+                let mut greens = vec![];
+                let mut green_cells = vec![];
+                let mut yellows = vec![];
+                let mut yellow_cells = vec![];
+                let mut color_cells = vec![];
+                for i in 0..LEN {
+                    let char = chars[i].map(|c| c.evaluate());
+                    word_cells.push(region.assign_advice(|| "input word characters", self.chars[i], 0, || char)?);
+                    final_cells.push(region.assign_advice(|| "final word characters", self.chars[i], 1, || final_chars[i].value().copied())?);
+                    region.constrain_equal(final_cells[i].cell(), final_chars[i].cell())?;
+
+                    let diff_g = char - final_cells[i].value().copied();
+                    region.assign_advice(|| "diff_g", self.chars[i], 2, || diff_g)?;
+                    diffs_green_is_zero_chips[i].assign(&mut region, 2, diff_g)?;
+
+                    let green = diff_g.map(|v| if v == F::zero() { F::one() } else { F::zero() });
+                    green_cells.push(region.assign_advice(|| "green", self.chars[i], GREEN_OFFSET, || green)?);
+                    greens.push(green);
+                }
+
+                let unmatched = greens.iter().fold(Value::known(F::from(LEN as u64)), |acc, green| acc - *green);
+                IsZeroChip::construct(self.won_is_zero.clone()).assign(&mut region, 1, unmatched)?;
+                let won = unmatched.map(|v| if v == F::zero() { F::one() } else { F::zero() });
+                let won_cell = region.assign_advice(|| "won", self.poly_word, 5, || won)?;
+
+                let mut eq_final = vec![];
+                for s in 0..LEN {
+                    let shifted: Vec<_> = (0..LEN).map(|i| final_cells[(i + s) % LEN].clone()).collect();
+                    eq_final.push(self.assign_eq(&mut region, EQ_FINAL_OFFSET + 3 * s, &word_cells, &shifted)?);
+                }
+
+                let mut eq_guess = vec![];
+                for s in 1..LEN {
+                    let shifted: Vec<_> = (0..LEN).map(|i| word_cells[(i + s) % LEN].clone()).collect();
+                    eq_guess.push(self.assign_eq(&mut region, Self::EQ_GUESS_OFFSET + 3 * (s - 1), &word_cells, &shifted)?);
+                }
+
+                // yellows depend on the yellows to their left, so fill them in order
+                for i in 0..LEN {
+                    let mut diff_y = Value::known(F::zero());
+                    for s in 0..LEN {
+                        let green = greens[(i + s) % LEN];
+                        diff_y = diff_y + eq_final[s][i] * (Value::known(F::one()) - green);
+                    }
+                    for s in 1..LEN {
+                        let k = (i + s) % LEN;
+                        if k < i {
+                            diff_y = diff_y - eq_guess[s - 1][i] * yellows[k];
                         }
+                    }
+                    region.assign_advice(|| "diff_y", self.chars[i], Self::YELLOW_OFFSET, || diff_y)?;
+                    diffs_yellow_is_zero_chips[i].assign(&mut region, Self::YELLOW_OFFSET, diff_y)?;
+
+                    let yellow = greens[i].zip(diff_y).map(|(green, diff_y)| {
+                        if green == F::zero() && diff_y != F::zero() { F::one() } else { F::zero() }
                     });
-                    // println!("i: {:?} diff_g_is_zero: {:?}", i, diff_g_is_zero);
-                    region.assign_advice(|| "diff_g_is_zero", self.chars[i], 4, || diff_g_is_zero)?;
-                    let diff_y_is_zero = diffs_yellow[i].and_then(|v| {
-                        if v == F::zero() {
-                            Value::known(F::one())
-                        } else {
-                            Value::known(F::zero())
+                    yellow_cells.push(region.assign_advice(|| "yellow", self.chars[i], Self::YELLOW_OFFSET + 1, || yellow)?);
+                    yellows.push(yellow);
+
+                    color_cells.push(match self.char_color_instance {
+                        Some(char_color_instance) => region.assign_advice_from_instance(|| "color",
+                            char_color_instance, instance_offset * LEN + i, self.chars[i], Self::COLOR_OFFSET)?,
+                        None => {
+                            let color = greens[i] * Value::known(F::from(GREEN)) + yellow;
+                            region.assign_advice(|| "color", self.chars[i], Self::COLOR_OFFSET, || color)?
                         }
                     });
-                    region.assign_advice(|| "diff_y_is_zero", self.chars[i], 5, || diff_y_is_zero)?;
+                }
+
+                if let Some(prev) = prev {
+                    self.q_hard.enable(&mut region, Self::HARD_OFFSET)?;
+
+                    let hard = region.assign_advice_from_instance(|| "hard mode", self.hard_mode_instance, 0, self.poly_word, Self::HARD_OFFSET)?;
+                    region.assign_advice(|| "hard mode enforced", self.poly_word, Self::HARD_OFFSET + 1, || hard.value().copied() * active)?;
+
+                    for i in 0..LEN {
+                        let prev_char = region.assign_advice(|| "prev char", self.chars[i], Self::HARD_OFFSET, || prev.chars[i].value().copied())?;
+                        region.constrain_equal(prev_char.cell(), prev.chars[i].cell())?;
+                        let prev_green = region.assign_advice(|| "prev green", self.chars[i], Self::HARD_OFFSET + 1, || prev.greens[i].value().copied())?;
+                        region.constrain_equal(prev_green.cell(), prev.greens[i].cell())?;
+                        let prev_yellow = region.assign_advice(|| "prev yellow", self.chars[i], Self::HARD_OFFSET + 2, || prev.yellows[i].value().copied())?;
+                        region.constrain_equal(prev_yellow.cell(), prev.yellows[i].cell())?;
+
+                        let partial = (0..LEN - 2).fold(Value::known(F::one()), |acc, j| {
+                            acc * (prev_char.value().copied() - word_cells[j].value().copied())
+                        });
+                        region.assign_advice(|| "prev char missing", self.chars[i], Self::HARD_OFFSET + 3, || partial)?;
+                    }
+                }
+
+                Ok(WordState {
+                    poly_word: poly_word_cell.0,
+                    active: active_cell,
+                    count: count_cell,
+                    won: won_cell,
+                    chars: word_cells,
+                    greens: green_cells,
+                    yellows: yellow_cells,
+                    colors: color_cells,
+                })
+            },
+        )
+    }
+
+    // A guess shared by every board of a multi-board game, range checked and looked up in the
+    // dictionary once.
+    pub fn assign_guess(
+        &self,
+        mut layouter: impl Layouter<F>,
+        poly_word: Value<Assigned<F>>,
+        chars: [Value<Assigned<F>>; LEN],
+    ) -> Result<(AssignedCell<F, F>, Vec<AssignedCell<F, F>>), Error> {
+        layouter.assign_region(
+            || "shared guess",
+            |mut region| {
+                self.q_input.enable(&mut region, 0)?;
+                self.q_guess.enable(&mut region, 0)?;
+
+                let poly_word = region.assign_advice(|| "poly word", self.poly_word, 0, || poly_word.map(|p| p.evaluate()))?;
+                let mut char_cells = vec![];
+                for i in 0..LEN {
+                    char_cells.push(region.assign_advice(|| "guess char", self.chars[i], 0, || chars[i].map(|c| c.evaluate()))?);
+                }
 
-                    region.assign_advice_from_instance(|| "color green",
-                    self.char_green_instance, instance_offset * WORD_LEN + i, self.chars[i], 6)?;
-                    region.assign_advice_from_instance(|| "color yellow",
-                    self.char_yellow_instance, instance_offset * WORD_LEN + i, self.chars[i], 7)?;
+                Ok((poly_word, char_cells))
+            },
+        )
+    }
+
+    // Ties a board's word to the shared guess: row 0 holds the guess, row 1 the board's word,
+    // which must be the guess times the board's active flag in row 2.
+    pub fn assign_mask(
+        &self,
+        mut layouter: impl Layouter<F>,
+        guess: &(AssignedCell<F, F>, Vec<AssignedCell<F, F>>),
+        word: &WordState<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "board mask",
+            |mut region| {
+                self.q_mask.enable(&mut region, 0)?;
+
+                let (guess_poly_word, guess_chars) = guess;
+                let cell = region.assign_advice(|| "guess poly word", self.poly_word, 0, || guess_poly_word.value().copied())?;
+                region.constrain_equal(cell.cell(), guess_poly_word.cell())?;
+                let cell = region.assign_advice(|| "board poly word", self.poly_word, 1, || word.poly_word.value().map(|p| p.evaluate()))?;
+                region.constrain_equal(cell.cell(), word.poly_word.cell())?;
+                let cell = region.assign_advice(|| "board active", self.poly_word, 2, || word.active.value().copied())?;
+                region.constrain_equal(cell.cell(), word.active.cell())?;
+
+                for i in 0..LEN {
+                    let cell = region.assign_advice(|| "guess char", self.chars[i], 0, || guess_chars[i].value().copied())?;
+                    region.constrain_equal(cell.cell(), guess_chars[i].cell())?;
+                    let cell = region.assign_advice(|| "board char", self.chars[i], 1, || word.chars[i].value().copied())?;
+                    region.constrain_equal(cell.cell(), word.chars[i].cell())?;
                 }
 
                 Ok(())
             },
         )
     }
-}
 
+    pub fn assign_game_over(
+        &self,
+        mut layouter: impl Layouter<F>,
+        last: &WordState<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "game over check",
+            |mut region| {
+                self.q_game_over.enable(&mut region, 0)?;
+
+                let active = region.assign_advice(|| "last active", self.poly_word, 0, || last.active.value().copied())?;
+                region.constrain_equal(active.cell(), last.active.cell())?;
+                let won = region.assign_advice(|| "last won", self.poly_word, 1, || last.won.value().copied())?;
+                region.constrain_equal(won.cell(), last.won.cell())?;
+
+                Ok(())
+            },
+        )
+    }
+
+    // The grid as the base 3 number with the colors as digits, first word first.
+    pub fn assign_packed_grid(
+        &self,
+        mut layouter: impl Layouter<F>,
+        words: &[WordState<F>],
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let colors: Vec<_> = words.iter().flat_map(|word| word.colors.iter()).collect();
+
+        layouter.assign_region(
+            || "grid packing",
+            |mut region| {
+                let mut acc = region.assign_advice_from_constant(|| "packed grid", self.poly_word, 0, F::zero())?;
+                for (offset, color) in colors.iter().enumerate() {
+                    self.q_pack.enable(&mut region, offset)?;
+
+                    let digit = region.assign_advice(|| "color", self.chars[0], offset, || color.value().copied())?;
+                    region.constrain_equal(digit.cell(), color.cell())?;
+
+                    let next = acc.value().copied() * Value::known(F::from(GREEN + 1)) + digit.value().copied();
+                    acc = region.assign_advice(|| "packed grid", self.poly_word, offset + 1, || next)?;
+                }
+
+                Ok(acc)
+            },
+        )
+    }
+
+    fn hash<const L: usize>(
+        &self,
+        layouter: impl Layouter<F>,
+        message: [AssignedCell<F, F>; L],
+    ) -> Result<AssignedCell<F, F>, Error> {
+        poseidon_hash(self.poseidon.clone(), self.poly_word, layouter, message)
+    }
+
+    // Constrains the public player tag, Poseidon(id), the player's registration,
+    // Poseidon(id, secret), and the day's nullifier, Poseidon(secret, day). The registration
+    // ties the nullifier to the player the tag names. Returns the id cell so variants can say
+    // who the player is.
+    pub fn assign_player(
+        &self,
+        mut layouter: impl Layouter<F>,
+        id: Value<F>,
+        secret: Value<F>,
+        day: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let (id, secret) = layouter.assign_region(
+            || "player",
+            |mut region| {
+                let id = region.assign_advice(|| "player id", self.poly_word, 0, || id)?;
+                let secret = region.assign_advice(|| "player secret", self.poly_word, 1, || secret)?;
+
+                Ok((id, secret))
+            },
+        )?;
+
+        let tag = self.hash(layouter.namespace(|| "player tag"), [id.clone()])?;
+        layouter.constrain_instance(tag.cell(), self.player_tag_instance, 0)?;
 
-#[derive(Default, Clone)]
-pub struct WordleCircuit<F: FieldExt> {
-    pub poly_words: [Value<Assigned<F>>; WORD_COUNT],
-    pub word_chars: [[Value<Assigned<F>>; WORD_LEN]; WORD_COUNT],
-    pub word_diffs_green: [[Value<F>; WORD_LEN]; WORD_COUNT],
-    pub word_diffs_yellow: [[Value<F>; WORD_LEN]; WORD_COUNT],
+        let registration = self.hash(layouter.namespace(|| "registration"), [id.clone(), secret.clone()])?;
+        layouter.constrain_instance(registration.cell(), self.registration_instance, 0)?;
+
+        let nullifier = self.hash(layouter.namespace(|| "nullifier"), [secret, day.clone()])?;
+        layouter.constrain_instance(nullifier.cell(), self.nullifier_instance, 0)?;
+
+        Ok(id)
+    }
 }
 
-impl<F: FieldExt> Circuit<F> for WordleCircuit<F>
-{
-    type Config = WordCheckConfig<F>;
-    type FloorPlanner = V1;
+/// A game of up to `GUESSES` guesses of `LEN` letter words. The defaults are the classic
+/// five letter, six guess game; note the bundled dictionary only has five letter words.
+#[derive(Clone)]
+pub struct WordleCircuit<F: FieldExt, const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub poly_words: [Value<Assigned<F>>; GUESSES],
+    pub word_chars: [[Value<Assigned<F>>; LEN]; GUESSES],
+    pub final_poly_word: Value<Assigned<F>>,
+    pub final_chars: [Value<Assigned<F>>; LEN],
+    pub salt: Value<F>,
+    pub player_id: Value<F>,
+    pub player_secret: Value<F>,
+    pub dictionary: Dictionary,
+}
 
-    fn without_witnesses(&self) -> Self {
-        Self::default()
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Default for WordleCircuit<F, LEN, GUESSES> {
+    fn default() -> Self {
+        Self {
+            poly_words: [Value::unknown(); GUESSES],
+            word_chars: [[Value::unknown(); LEN]; GUESSES],
+            final_poly_word: Value::unknown(),
+            final_chars: [Value::unknown(); LEN],
+            salt: Value::unknown(),
+            player_id: Value::unknown(),
+            player_secret: Value::unknown(),
+            dictionary: Dictionary::default(),
+        }
     }
+}
 
-    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> WordleCircuit<F, LEN, GUESSES>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    // Shared by the circuit variants, which differ in how guesses are checked against the
+    // dictionary and whether the grid is public.
+    fn configure_with(meta: &mut ConstraintSystem<F>, merkle_dictionary: bool, private_grid: bool) -> WordCheckConfig<F, LEN> {
         let q_input = meta.complex_selector();
+        let q_guess = meta.complex_selector();
+        let q_answer = meta.complex_selector();
         let q_diff_g = meta.selector();
         let q_diff_y = meta.selector();
-        let q_diff_green_is_zero = meta.complex_selector();
-        let q_diff_yellow_is_zero = meta.complex_selector();
-        let q_color_is_zero = meta.selector();
+        let q_diff_green_is_zero = meta.selector();
+        let q_diff_yellow_is_zero = meta.selector();
+        let q_eq = meta.selector();
         let q_color = meta.selector();
+        let q_active = meta.selector();
+        let q_game_over = meta.selector();
+        let q_hard = meta.selector();
+        let q_pack = meta.selector();
+        let q_mask = meta.selector();
 
         let poly_word = meta.advice_column();
-        let chars = [
-            meta.advice_column(),
-            meta.advice_column(),
-            meta.advice_column(),
-            meta.advice_column(),
-            meta.advice_column()            
-        ];
-        let color_is_zero_advice_column = [
-            meta.advice_column(),
-            meta.advice_column(),
+        let chars = [(); LEN].map(|_| meta.advice_column());
+        let color_is_zero_advice_column = [(); LEN].map(|_| meta.advice_column());
+        let poseidon_state = [
             meta.advice_column(),
             meta.advice_column(),
             meta.advice_column()
         ];
-        let final_word_chars_instance = meta.instance_column();
-        let char_green_instance = meta.instance_column();
-        let char_yellow_instance = meta.instance_column();
+        let poseidon_partial_sbox = meta.advice_column();
+        let poseidon_rc_a = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column()
+        ];
+        let poseidon_rc_b = [
+            meta.fixed_column(),
+            meta.fixed_column(),
+            meta.fixed_column()
+        ];
+        let commitment_instance = meta.instance_column();
+        let day_instance = meta.instance_column();
+        let guess_count_instance = meta.instance_column();
+        let hard_mode_instance = meta.instance_column();
+        let char_color_instance = if private_grid {
+            None
+        } else {
+            Some(meta.instance_column())
+        };
+        let player_tag_instance = meta.instance_column();
+        let registration_instance = meta.instance_column();
+        let nullifier_instance = meta.instance_column();
+        let dictionary_root_instance = if merkle_dictionary {
+            Some(meta.instance_column())
+        } else {
+            None
+        };
 
         WordCheckConfig::configure(meta,
             q_input,
+            q_guess,
+            q_answer,
             q_diff_g,
             q_diff_y,
             q_diff_green_is_zero,
             q_diff_yellow_is_zero,
-            q_color_is_zero,
+            q_eq,
             q_color,
+            q_active,
+            q_game_over,
+            q_hard,
+            q_pack,
+            q_mask,
             poly_word,
             chars,
             color_is_zero_advice_column,
-            final_word_chars_instance,
-            char_green_instance,
-            char_yellow_instance,
+            commitment_instance,
+            day_instance,
+            guess_count_instance,
+            hard_mode_instance,
+            char_color_instance,
+            player_tag_instance,
+            registration_instance,
+            nullifier_instance,
+            poseidon_state,
+            poseidon_partial_sbox,
+            poseidon_rc_a,
+            poseidon_rc_b,
+            dictionary_root_instance,
         )
     }
 
-    fn synthesize(
+    fn synthesize_with(
         &self,
-        config: Self::Config,
+        config: WordCheckConfig<F, LEN>,
         mut layouter: impl Layouter<F>,
-    ) -> Result<(), Error> {
-        config.table.load(&mut layouter)?;
-
-        for idx in 0..WORD_COUNT {
-            // println!("idx {:?} diffs_green: {:?}", idx, self.word_diffs_green[idx]);
-            config.assign_word(
+        paths: Option<&[MerklePath<F>; GUESSES]>,
+    ) -> Result<GameCells<F>, Error> {
+        config.load(&mut layouter, &self.dictionary)?;
+
+        let (final_chars, day) = config.assign_final_word(
+            layouter.namespace(|| "final word"),
+            self.final_poly_word,
+            self.final_chars,
+            self.salt,
+            0,
+        )?;
+
+        let mut words: Vec<WordState<F>> = vec![];
+        for idx in 0..GUESSES {
+            let state = config.assign_word(
                 layouter.namespace(|| format!("word {}", idx)),
                 self.poly_words[idx],
                 self.word_chars[idx],
-                self.word_diffs_green[idx],
-                self.word_diffs_yellow[idx],
+                words.last(),
+                &final_chars,
                 idx,
+                true,
             )?;
+            if let (GuessMembership::Merkle(merkle), Some(paths)) = (&config.guess_membership, paths) {
+                merkle.assign_path(layouter.namespace(|| format!("word {} merkle path", idx)), &state.poly_word, &paths[idx])?;
+            }
+            words.push(state);
         }
-        Ok(())
-    }
-}
 
+        let last = words.last().unwrap();
+        config.assign_game_over(layouter.namespace(|| "game over"), last)?;
+        layouter.constrain_instance(last.count.cell(), config.guess_count_instance, 0)?;
+        let player_id = config.assign_player(layouter.namespace(|| "player"), self.player_id, self.player_secret, &day)?;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_wordle_1() {
-        let k = 14;
+        Ok(GameCells {
+            day,
+            player_id,
+            words,
+        })
+    }
+}
 
-        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff"), String::from("fluff"), String::from("fluff")];
-        
-        let mut poly_words: [Value<Assigned<Fp>>; WORD_COUNT] = [Value::known(Fp::from(123).into()); WORD_COUNT];
-        let mut word_chars: [[Value<Assigned<Fp>>; WORD_LEN]; WORD_COUNT] = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Circuit<F> for WordleCircuit<F, LEN, GUESSES>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    type Config = WordCheckConfig<F, LEN>;
+    type FloorPlanner = V1;
 
-        for idx in 0..WORD_COUNT {
-            poly_words[idx] = Value::known(Fp::from(word_to_polyhash(&words[idx].clone())).into());
-            let chars = word_to_chars(&words[idx].clone());
-            for i in 0..WORD_LEN {
-                word_chars[idx][i] = Value::known(Fp::from(chars[i]).into());
-            }
+    // The dictionary is part of the circuit, not the witness.
+    fn without_witnesses(&self) -> Self {
+        Self {
+            dictionary: self.dictionary.clone(),
+            ..Self::default()
         }
+    }
 
-        let final_word = String::from("fluff");
-        let final_chars = word_to_chars(&final_word);
-
-        let mut word_diffs_green = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-        let mut word_diffs_yellow = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-        for idx in 0..WORD_COUNT {
-            let chars = word_to_chars(&words[idx].clone());
-            for i in 0..WORD_LEN {
-                word_diffs_green[idx][i] = Value::known((Fp::from(chars[i]) - Fp::from(final_chars[i])).into());
-            }
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        Self::configure_with(meta, false, false)
+    }
 
-            for i in 0..WORD_LEN {
-                let yellow_diff = {
-                    (0..WORD_LEN).fold(Fp::from(1), |expr, j| {
-                        expr * (Fp::from(chars[i]) - Fp::from(final_chars[j]))
-                    })
-                };
-                word_diffs_yellow[idx][i] = Value::known(Fp::from(yellow_diff).into());
-            }
-        }
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        self.synthesize_with(config, layouter, None).map(|_| ())
+    }
+}
 
-        // println!("word_diffs_green {:?}", word_diffs_green);
-        // println!("{:?}", word_diffs_yellow);
+/// A `WordleCircuit` whose guesses are checked against a dictionary root, the last public
+/// input, instead of a table baked into the keys. The solution is still checked against
+/// `game.dictionary.answers`, and `game.dictionary.guesses` is unused. `MerklePublicInputs`
+/// lays out the public inputs.
+#[derive(Clone)]
+pub struct MerkleWordleCircuit<F: FieldExt, const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub game: WordleCircuit<F, LEN, GUESSES>,
+    pub paths: [MerklePath<F>; GUESSES],
+}
 
-        // Successful cases
-        let circuit = WordleCircuit::<Fp> {
-            poly_words,
-            word_chars,
-            word_diffs_green,
-            word_diffs_yellow,
-        };
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Default for MerkleWordleCircuit<F, LEN, GUESSES> {
+    fn default() -> Self {
+        Self {
+            game: WordleCircuit::default(),
+            paths: [MerklePath::default(); GUESSES],
+        }
+    }
+}
 
-        let mut instance = Vec::new();
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Circuit<F> for MerkleWordleCircuit<F, LEN, GUESSES>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    type Config = WordCheckConfig<F, LEN>;
+    type FloorPlanner = V1;
 
-        // final word chars
-        let mut final_chars_instance = vec![];
-        for i in 0..WORD_LEN {
-            final_chars_instance.push(Fp::from(final_chars[i]));
+    fn without_witnesses(&self) -> Self {
+        Self {
+            game: self.game.without_witnesses(),
+            paths: [MerklePath::default(); GUESSES],
         }
-        instance.push(final_chars_instance);
+    }
 
-        let mut diffs = vec![];
-        for idx in 0..WORD_COUNT {
-            diffs.push(compute_diff(&words[idx], &final_word));
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        WordleCircuit::<F, LEN, GUESSES>::configure_with(meta, true, false)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        self.game.synthesize_with(config, layouter, Some(&self.paths)).map(|_| ())
+    }
+}
+
+/// A `WordleCircuit` that proves the puzzle was solved in the public guess count without
+/// showing the grid: the colors are private witnesses under the same color checks, and the
+/// colors column is dropped from the public inputs, see `PrivateGridPublicInputs`.
+#[derive(Clone)]
+pub struct PrivateGridWordleCircuit<F: FieldExt, const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub game: WordleCircuit<F, LEN, GUESSES>,
+}
+
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Default for PrivateGridWordleCircuit<F, LEN, GUESSES> {
+    fn default() -> Self {
+        Self {
+            game: WordleCircuit::default(),
         }
+    }
+}
 
-        // color green
-        let mut green = vec![];
-        for idx in 0..WORD_COUNT {
-            for i in 0..WORD_LEN {
-                green.push(diffs[idx][0][i]);
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Circuit<F> for PrivateGridWordleCircuit<F, LEN, GUESSES>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    type Config = WordCheckConfig<F, LEN>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            game: self.game.without_witnesses(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        WordleCircuit::<F, LEN, GUESSES>::configure_with(meta, false, true)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        self.game.synthesize_with(config, layouter, None).map(|_| ())
+    }
+}
+
+/// Dordle (`BOARDS = 2`), Quordle (`BOARDS = 4`) and the like: every guess is played on
+/// `BOARDS` boards at once, each with its own hidden solution, and a board stops taking
+/// guesses once it's solved. The game is won when every board is.
+///
+/// Public inputs are as for `WordleCircuit`, except that there is a commitment and a guess
+/// count per board, and the colors are one grid per board, first board first.
+/// `MultiPublicInputs` lays them out.
+#[derive(Clone)]
+pub struct MultiWordleCircuit<F: FieldExt, const BOARDS: usize, const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub poly_words: [Value<Assigned<F>>; GUESSES],
+    pub word_chars: [[Value<Assigned<F>>; LEN]; GUESSES],
+    pub final_poly_words: [Value<Assigned<F>>; BOARDS],
+    pub final_chars: [[Value<Assigned<F>>; LEN]; BOARDS],
+    pub salt: Value<F>,
+    pub player_id: Value<F>,
+    pub player_secret: Value<F>,
+    pub dictionary: Dictionary,
+}
+
+impl<F: FieldExt, const BOARDS: usize, const LEN: usize, const GUESSES: usize> Default for MultiWordleCircuit<F, BOARDS, LEN, GUESSES> {
+    fn default() -> Self {
+        Self {
+            poly_words: [Value::unknown(); GUESSES],
+            word_chars: [[Value::unknown(); LEN]; GUESSES],
+            final_poly_words: [Value::unknown(); BOARDS],
+            final_chars: [[Value::unknown(); LEN]; BOARDS],
+            salt: Value::unknown(),
+            player_id: Value::unknown(),
+            player_secret: Value::unknown(),
+            dictionary: Dictionary::default(),
+        }
+    }
+}
+
+impl<F: FieldExt, const BOARDS: usize, const LEN: usize, const GUESSES: usize> Circuit<F> for MultiWordleCircuit<F, BOARDS, LEN, GUESSES>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    type Config = WordCheckConfig<F, LEN>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            dictionary: self.dictionary.clone(),
+            ..Self::default()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        WordleCircuit::<F, LEN, GUESSES>::configure_with(meta, false, false)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.load(&mut layouter, &self.dictionary)?;
+
+        let mut finals = vec![];
+        let mut day = None;
+        for board in 0..BOARDS {
+            let (final_chars, day_cell) = config.assign_final_word(
+                layouter.namespace(|| format!("board {} final word", board)),
+                self.final_poly_words[board],
+                self.final_chars[board],
+                self.salt,
+                board,
+            )?;
+            finals.push(final_chars);
+            day.get_or_insert(day_cell);
+        }
+
+        let mut boards: Vec<Vec<WordState<F>>> = vec![vec![]; BOARDS];
+        for idx in 0..GUESSES {
+            let guess = config.assign_guess(
+                layouter.namespace(|| format!("guess {}", idx)),
+                self.poly_words[idx],
+                self.word_chars[idx],
+            )?;
+
+            for board in 0..BOARDS {
+                // what the board sees: the guess while it's active, zeros after it's solved
+                let prev = boards[board].last();
+                let active = match prev {
+                    Some(prev) => prev.active.value().copied() * (Value::known(F::one()) - prev.won.value().copied()),
+                    None => Value::known(F::one()),
+                };
+                let poly_word = self.poly_words[idx].zip(active).map(|(p, active)| p * Assigned::from(active));
+                let chars = self.word_chars[idx].map(|c| c.zip(active).map(|(c, active)| c * Assigned::from(active)));
+
+                let state = config.assign_word(
+                    layouter.namespace(|| format!("board {} word {}", board, idx)),
+                    poly_word,
+                    chars,
+                    prev,
+                    &finals[board],
+                    board * GUESSES + idx,
+                    false,
+                )?;
+                config.assign_mask(layouter.namespace(|| format!("board {} word {} mask", board, idx)), &guess, &state)?;
+                boards[board].push(state);
             }
         }
-        instance.push(green);
 
-        // color yellow
-        let mut yellow = vec![];
+        for (board, words) in boards.iter().enumerate() {
+            let last = words.last().unwrap();
+            config.assign_game_over(layouter.namespace(|| format!("board {} game over", board)), last)?;
+            layouter.constrain_instance(last.count.cell(), config.guess_count_instance, board)?;
+        }
+        config.assign_player(layouter.namespace(|| "player"), self.player_id, self.player_secret, &day.unwrap())?;
+
+        Ok(())
+    }
+}
+
+
+/// The host's side of live play: proves that the colors handed back for a guess are right
+/// for the committed solution, so a client can refuse to play on with a host that lies.
+///
+/// Public inputs use `WordleCircuit`'s columns, of which only the commitment, the day and
+/// the first row of colors are filled in; the player's columns are left empty. The guess's
+/// poly word follows as an extra column. `FeedbackPublicInputs` lays them out.
+#[derive(Clone)]
+pub struct FeedbackCircuit<F: FieldExt, const LEN: usize = WORD_LEN> {
+    pub guess_poly_word: Value<Assigned<F>>,
+    pub guess_chars: [Value<Assigned<F>>; LEN],
+    pub final_poly_word: Value<Assigned<F>>,
+    pub final_chars: [Value<Assigned<F>>; LEN],
+    pub salt: Value<F>,
+    pub dictionary: Dictionary,
+}
+
+impl<const LEN: usize> FeedbackCircuit<Fp, LEN> {
+    // Checks the guess against the bundled dictionary, set `dictionary` to use another.
+    pub fn new(guess: &Word<LEN>, final_word: &Word<LEN>, salt: Fp) -> Self {
+        let (guess_poly_word, guess_chars) = word_witness(guess);
+        let (final_poly_word, final_chars) = word_witness(final_word);
+
+        Self {
+            guess_poly_word,
+            guess_chars,
+            final_poly_word,
+            final_chars,
+            salt: Value::known(salt),
+            dictionary: Dictionary::default(),
+        }
+    }
+}
+
+impl<F: FieldExt, const LEN: usize> Default for FeedbackCircuit<F, LEN> {
+    fn default() -> Self {
+        Self {
+            guess_poly_word: Value::unknown(),
+            guess_chars: [Value::unknown(); LEN],
+            final_poly_word: Value::unknown(),
+            final_chars: [Value::unknown(); LEN],
+            salt: Value::unknown(),
+            dictionary: Dictionary::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeedbackConfig<F: FieldExt, const LEN: usize> {
+    game: WordCheckConfig<F, LEN>,
+    guess_instance: Column<Instance>,
+}
+
+impl<F: FieldExt, const LEN: usize> Circuit<F> for FeedbackCircuit<F, LEN>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    type Config = FeedbackConfig<F, LEN>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            dictionary: self.dictionary.clone(),
+            ..Self::default()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let game = WordleCircuit::<F, LEN, 1>::configure_with(meta, false, false);
+
+        let guess_instance = meta.instance_column();
+        meta.enable_equality(guess_instance);
+
+        FeedbackConfig { game, guess_instance }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.game.load(&mut layouter, &self.dictionary)?;
+
+        let (final_chars, _) = config.game.assign_final_word(
+            layouter.namespace(|| "final word"),
+            self.final_poly_word,
+            self.final_chars,
+            self.salt,
+            0,
+        )?;
+
+        // as the first word of a game, so it's checked whatever came before it
+        let guess = config.game.assign_word(
+            layouter.namespace(|| "guess"),
+            self.guess_poly_word,
+            self.guess_chars,
+            None,
+            &final_chars,
+            0,
+            true,
+        )?;
+        layouter.constrain_instance(guess.poly_word.cell(), config.guess_instance, 0)
+    }
+}
+
+/// A `WordleCircuit` that reveals the guesses of the rows the prover picks, say just the
+/// starting word. Two public inputs follow the `WordleCircuit` ones: a 0/1 flag per row,
+/// and per row the revealed poly word, which is 0 for hidden rows. `DisclosingPublicInputs`
+/// fills both in from the words and the rows to reveal.
+#[derive(Clone)]
+pub struct DisclosingWordleCircuit<F: FieldExt, const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub game: WordleCircuit<F, LEN, GUESSES>,
+}
+
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Default for DisclosingWordleCircuit<F, LEN, GUESSES> {
+    fn default() -> Self {
+        Self {
+            game: WordleCircuit::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DisclosingWordleConfig<F: FieldExt, const LEN: usize> {
+    game: WordCheckConfig<F, LEN>,
+    disclosure: DisclosureConfig,
+}
+
+impl<F: FieldExt, const LEN: usize, const GUESSES: usize> Circuit<F> for DisclosingWordleCircuit<F, LEN, GUESSES>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    type Config = DisclosingWordleConfig<F, LEN>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            game: self.game.without_witnesses(),
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let game = WordleCircuit::<F, LEN, GUESSES>::configure_with(meta, false, false);
+
+        let reveal_instance = meta.instance_column();
+        let revealed_word_instance = meta.instance_column();
+        let disclosure = DisclosureConfig::configure(meta, game.poly_word, reveal_instance, revealed_word_instance);
+
+        DisclosingWordleConfig { game, disclosure }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let game = self.game.synthesize_with(config.game, layouter.namespace(|| "game"), None)?;
+        config.disclosure.assign(layouter.namespace(|| "disclosure"), &game.words)
+    }
+}
+
+/// A `WordleCircuit` where the player signs the (day, grid) result, with a Schnorr signature
+/// over Pallas checked in-circuit. The player id is the signing key's `key_id`, so only the
+/// key holder can produce a proof for the tag and registration derived from it.
+///
+/// The signed message is Poseidon(day, packed grid), see `compute_result_message`.
+#[derive(Clone)]
+pub struct SignedWordleCircuit<const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub game: WordleCircuit<Fp, LEN, GUESSES>,
+    pub public_key: Value<pallas::Affine>,
+    pub signature: Value<Signature>,
+}
+
+impl<const LEN: usize, const GUESSES: usize> Default for SignedWordleCircuit<LEN, GUESSES> {
+    fn default() -> Self {
+        Self {
+            game: WordleCircuit::default(),
+            public_key: Value::unknown(),
+            signature: Value::unknown(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SignedWordleConfig<const LEN: usize> {
+    game: WordCheckConfig<Fp, LEN>,
+    signature: SignatureConfig,
+}
+
+impl<const LEN: usize, const GUESSES: usize> Circuit<Fp> for SignedWordleCircuit<LEN, GUESSES> {
+    type Config = SignedWordleConfig<LEN>;
+    type FloorPlanner = V1;
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            game: self.game.without_witnesses(),
+            ..Self::default()
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+        let game = WordleCircuit::<Fp, LEN, GUESSES>::configure_with(meta, false, false);
+
+        let advices = [(); 10].map(|_| meta.advice_column());
+        let lagrange_coeffs = [(); 8].map(|_| meta.fixed_column());
+        let signature = SignatureConfig::configure(meta, advices, lagrange_coeffs, game.poseidon.clone());
+
+        SignedWordleConfig { game, signature }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fp>,
+    ) -> Result<(), Error> {
+        config.signature.load(&mut layouter)?;
+
+        let game = self.game.synthesize_with(config.game.clone(), layouter.namespace(|| "game"), None)?;
+
+        let grid = config.game.assign_packed_grid(layouter.namespace(|| "grid"), &game.words)?;
+        let message = config.signature.hash(layouter.namespace(|| "result message"), [game.day, grid])?;
+        let signer = config.signature.verify(layouter.namespace(|| "signature"), self.public_key, self.signature, message)?;
+
+        layouter.assign_region(
+            || "signer is player",
+            |mut region| region.constrain_equal(signer.cell(), game.player_id.cell()),
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_proofs::dev::{MockProver, VerifyFailure};
+
+    const DAY: u64 = 7;
+    const PLAYER: &str = "alice";
+    const PLAYER_SECRET: u64 = 0x5ec7;
+
+    fn word(word: &str) -> Word {
+        Word::new(word).unwrap()
+    }
+
+    // Built by hand rather than with `Play::new`, so tests can make games that shouldn't prove.
+    fn play<const LEN: usize, const GUESSES: usize>(words: &[String], final_word: &str, salt: Fp, hard_mode: bool) -> Play<LEN, GUESSES> {
+        Play {
+            words: words.iter().map(|guess| Word::new(guess).unwrap()).collect(),
+            final_word: Word::new(final_word).unwrap(),
+            salt,
+            day: DAY,
+            hard_mode,
+            player_id: handle_to_field(PLAYER).unwrap(),
+            player_secret: Fp::from(PLAYER_SECRET),
+            dictionary: Dictionary::default(),
+        }
+    }
+
+    fn wordle_circuit<const LEN: usize, const GUESSES: usize>(words: &[String], final_word: &str, salt: Fp) -> WordleCircuit<Fp, LEN, GUESSES> {
+        play::<LEN, GUESSES>(words, final_word, salt, false).circuit()
+    }
+
+    fn wordle_instance<const LEN: usize, const GUESSES: usize>(words: &[String], final_word: &str, salt: Fp, hard_mode: bool) -> Vec<Vec<Fp>> {
+        play::<LEN, GUESSES>(words, final_word, salt, hard_mode).public_inputs().to_instance_columns().unwrap()
+    }
+
+    #[test]
+    fn test_wordle_1() {
+        let k = 14;
+
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+
+        // Successful cases
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // A commitment to a different salt must not verify
+        let mut bad_instance = instance.clone();
+        bad_instance[0][0] = compute_commitment(&word("fluff"), Fp::from(43), DAY);
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Nor does passing the commitment off as another day's
+        let mut bad_instance = instance.clone();
+        bad_instance[1][0] = Fp::from(DAY + 1);
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Or claiming someone else's result as your own
+        let mut bad_instance = instance.clone();
+        bad_instance[5][0] = compute_player_tag(handle_to_field("mallory").unwrap());
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // A second submission for the day can't dodge the nullifier with a fresh secret, the
+        // registration pins the secret to the player
+        let mut other_secret = circuit.clone();
+        other_secret.player_secret = Value::known(Fp::from(PLAYER_SECRET + 1));
+        let mut bad_instance = instance.clone();
+        bad_instance[7][0] = compute_nullifier(Fp::from(PLAYER_SECRET + 1), DAY);
+        let prover = MockProver::run(k, &other_secret, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+        assert_ne!(compute_nullifier(Fp::from(PLAYER_SECRET), DAY), compute_nullifier(Fp::from(PLAYER_SECRET), DAY + 1));
+
+        // A fresh registration to go with it is only turned away by the one on file
+        let mut registrations = Registrations::default();
+        registrations.insert(instance[5][0], instance[6][0]);
+        assert!(registrations.matches(instance[5][0], instance[6][0]));
+        let fresh = compute_registration(handle_to_field(PLAYER).unwrap(), Fp::from(PLAYER_SECRET + 1));
+        assert!(!registrations.matches(instance[5][0], fresh));
+
+        // Neither does claiming the unused rows were played
+        let mut bad_instance = instance;
+        bad_instance[2][0] = Fp::from(WORD_COUNT as u64);
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+    }
+
+    #[test]
+    fn test_wordle_play() {
+        let words = vec![String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let new = |words: &[String], hard_mode: bool| {
+            let words = words.iter().map(|guess| word(guess)).collect();
+            Play::<WORD_LEN, WORD_COUNT>::new(words, word("fluff"), Fp::from(42), DAY, hard_mode, handle_to_field(PLAYER).unwrap(), Fp::from(PLAYER_SECRET), Dictionary::default())
+        };
+
+        assert_eq!(new(&words, true), Ok(play(&words, "fluff", Fp::from(42), true)));
+        assert!(new(&words[..2], false).is_err());
+        assert!(new(&[words.clone(), vec![String::from("fluff")]].concat(), false).is_err());
+        assert!(new(&[vec![String::from("audio"); WORD_COUNT], vec![String::from("fluff")]].concat(), false).is_err());
+        assert!(new(&[String::from("audio"), String::from("hunky"), String::from("skill"), String::from("fluff")], true).is_err());
+        assert_eq!(new(&[String::from("zzzzz"), String::from("fluff")], false), Err(WordleError::UnknownWord(String::from("zzzzz"))));
+    }
+
+    #[test]
+    fn test_wordle_word() {
+        assert_eq!(Word::<WORD_LEN>::new(" Fluff\n"), Ok(word("fluff")));
+        assert_eq!(word("fluff").chars(), word_to_chars("fluff").unwrap());
+        assert_eq!(word("fluff").poly_word(), word_to_polyhash("fluff").unwrap());
+        for bad in ["fluffy", "flu", "flu1f", "flu f", "flüff", ""] {
+            assert_eq!(Word::<WORD_LEN>::new(bad), Err(WordleError::InvalidWord(String::from(bad))));
+        }
+        assert!(Word::<MAX_WORD_LEN>::new("abcdefghijkl").is_ok());
+        assert!(Word::<14>::new("abcdefghijklmn").is_err());
+
+        assert!(word("fluff").is_in_dictionary());
+        assert!(!word("zzzzz").is_in_dictionary());
+    }
+
+    #[test]
+    fn test_wordle_polyhash() {
+        assert_eq!(polyhash_to_word(word_to_polyhash("audio").unwrap()).unwrap(), "audio");
+        assert!(polyhash_to_word(0).unwrap().is_empty());
+        assert!(polyhash_to_word(BASE).is_err());
+    }
+
+    #[test]
+    fn test_wordle_duplicate_letters() {
+        let k = 14;
+
+        let words = [String::from("lolly"), String::from("skill"), String::from("llama"), String::from("bluff"), String::from("stuff"), String::from("fluff")];
+
+        assert_eq!(compute_diff(&word("lolly"), &word("fluff")), vec![Tile::Yellow, Tile::Gray, Tile::Gray, Tile::Gray, Tile::Gray]);
+        assert_eq!(compute_diff(&word("skill"), &word("llama")), vec![Tile::Gray, Tile::Gray, Tile::Gray, Tile::Yellow, Tile::Yellow]);
+        assert_eq!(compute_diff(&word("allee"), &word("llama")), vec![Tile::Yellow, Tile::Green, Tile::Yellow, Tile::Gray, Tile::Gray]);
+
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // Every `l` in "lolly" claimed yellow, as the old any-position rule would have it
+        let mut bad_instance = instance;
+        bad_instance[4][2] = Fp::from(YELLOW);
+        bad_instance[4][3] = Fp::from(YELLOW);
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_must_win() {
+        let k = 14;
+
+        // Unfinished game
+        let words = [String::from("audio"), String::from("hunky")];
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Guessing on after the solve
+        let words = [String::from("audio"), String::from("fluff"), String::from("funky")];
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_hard_mode() {
+        let k = 14;
+
+        // every guess keeps the green f and the yellow u
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), true);
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        prover.assert_satisfied();
+
+        // "skill" drops the yellow u revealed by "hunky"
+        let words = [String::from("audio"), String::from("hunky"), String::from("skill"), String::from("fluff")];
+        assert!(!satisfies_hard_mode(&word("hunky"), &compute_diff(&word("hunky"), &word("fluff")), &word("skill")));
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        prover.assert_satisfied();
+
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), true);
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_answer_list() {
+        let k = 14;
+
+        // The bundled answers are common words, all of them valid guesses
+        let dictionary = Dictionary::default();
+        assert!(dictionary.is_answer(&word("fluff")));
+        assert!(dictionary.is_guess(&word("aahed")) && !dictionary.is_answer(&word("aahed")));
+        assert!(dictionary.answers.iter().all(|answer| dictionary.guesses.contains(answer)));
+
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+
+        let mut circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        circuit.dictionary.answers = vec![word_to_polyhash("fluff").unwrap(), word_to_polyhash("funky").unwrap()];
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // A valid guess is not necessarily a valid solution
+        circuit.dictionary.answers = vec![word_to_polyhash("funky").unwrap()];
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Nor is the zero the table is padded with
+        let mut circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        circuit.final_poly_word = Value::known(Fp::zero().into());
+        circuit.final_chars = [Value::known(Fp::zero().into()); WORD_LEN];
+        let mut bad_instance = instance;
+        bad_instance[0][0] = halo2_gadgets::poseidon::primitives::Hash::<_, P128Pow5T3, ConstantLength<3>, 3, 2>::init()
+            .hash([Fp::zero(), Fp::from(42), Fp::from(DAY)]);
+        let failures = MockProver::run(k, &circuit, bad_instance).unwrap().verify().unwrap_err();
+        assert!(failures.iter().any(|failure| matches!(
+            failure,
+            VerifyFailure::ConstraintNotSatisfied { constraint, .. } if constraint.to_string().contains("answer check")
+        )));
+    }
+
+    #[test]
+    fn test_wordle_merkle_dictionary() {
+        let k = 14;
+
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let dictionary: Vec<u64> = ["aahed", "audio", "fluff", "funky", "hunky", "skill"].iter().map(|word| word_to_polyhash(word).unwrap()).collect();
+        let tree = DictionaryTree::new(&dictionary).unwrap();
+
+        let game = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
+        let mut paths = [MerklePath::default(); WORD_COUNT];
         for idx in 0..WORD_COUNT {
-            for i in 0..WORD_LEN {
-                yellow.push(diffs[idx][1][i]);
+            let word = if idx < words.len() { word_to_polyhash(&words[idx]).unwrap() } else { 0 };
+            paths[idx] = tree.path(word).unwrap();
+        }
+        let circuit = MerkleWordleCircuit { game, paths };
+
+        let public_inputs = MerklePublicInputs {
+            game: play::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false).public_inputs(),
+            dictionary_root: tree.root(),
+        };
+        let instance = public_inputs.to_instance_columns().unwrap();
+        assert_eq!(MerklePublicInputs::from_instance_columns(&instance), Ok(public_inputs));
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // The same paths don't open to a dictionary without "hunky"
+        let other = DictionaryTree::new(&[dictionary[..4].to_vec(), dictionary[5..].to_vec()].concat()).unwrap();
+        let mut bad_instance = instance;
+        bad_instance[8][0] = other.root();
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_signed() {
+        use rand_core::OsRng;
+
+        let k = 14;
+
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let mut diffs: Vec<Vec<Tile>> = words.iter().map(|guess| compute_diff(&word(guess), &word("fluff"))).collect();
+        diffs.resize(WORD_COUNT, vec![Tile::Gray; WORD_LEN]);
+
+        let key = PlayerKey::random(OsRng);
+        assert!(format!("{:?}", key).contains("secret: \"<redacted>\""));
+        let mut game = wordle_circuit(&words, "fluff", Fp::from(42));
+        game.player_id = Value::known(key_id(key.public_key()));
+        let mut circuit = SignedWordleCircuit::<WORD_LEN, WORD_COUNT> {
+            game,
+            public_key: Value::known(key.public_key()),
+            signature: Value::known(key.sign(compute_result_message(DAY, &diffs), OsRng)),
+        };
+        let mut instance = wordle_instance::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+        instance[5][0] = compute_player_tag(key_id(key.public_key()));
+        instance[6][0] = compute_registration(key_id(key.public_key()), Fp::from(PLAYER_SECRET));
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // The result can't be claimed for another key
+        let other = PlayerKey::random(OsRng);
+        let mut other_circuit = circuit.clone();
+        other_circuit.game.player_id = Value::known(key_id(other.public_key()));
+        let mut bad_instance = instance.clone();
+        bad_instance[5][0] = compute_player_tag(key_id(other.public_key()));
+        bad_instance[6][0] = compute_registration(key_id(other.public_key()), Fp::from(PLAYER_SECRET));
+        let prover = MockProver::run(k, &other_circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Nor does a signature over another day's result carry over
+        circuit.signature = Value::known(key.sign(compute_result_message(DAY + 1, &diffs), OsRng));
+        let prover = MockProver::run(k, &circuit, instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_multi_board() {
+        let k = 14;
+
+        // "funky" solves the second board on the third guess, the first board needs four
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let finals = ["fluff", "funky"];
+
+        let game = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, finals[0], Fp::from(42));
+        let second = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, finals[1], Fp::from(42));
+        let circuit = MultiWordleCircuit::<Fp, 2> {
+            poly_words: game.poly_words,
+            word_chars: game.word_chars,
+            final_poly_words: [game.final_poly_word, second.final_poly_word],
+            final_chars: [game.final_chars, second.final_chars],
+            salt: game.salt,
+            player_id: game.player_id,
+            player_secret: game.player_secret,
+            dictionary: Dictionary::default(),
+        };
+
+        // the public inputs of each board played on its own, merged
+        let boards: Vec<_> = finals
+            .iter()
+            .map(|final_word| {
+                let solved = words.iter().position(|word| word == final_word).unwrap() + 1;
+                play::<WORD_LEN, WORD_COUNT>(&words[..solved], final_word, Fp::from(42), false).public_inputs()
+            })
+            .collect();
+        let public_inputs = MultiPublicInputs::<2>::new(&boards).unwrap();
+        let instance = public_inputs.to_instance_columns().unwrap();
+        assert_eq!(MultiPublicInputs::from_instance_columns(&instance), Ok(public_inputs.clone()));
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // The solved board doesn't take the later guesses
+        let mut bad_instance = instance.clone();
+        bad_instance[2][1] = Fp::from(words.len() as u64);
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // And every board has to be solved
+        let mut unsolved = circuit.clone();
+        unsolved.poly_words[3] = Value::known(Fp::zero().into());
+        unsolved.word_chars[3] = [Value::known(Fp::zero().into()); WORD_LEN];
+        let mut bad_inputs = public_inputs;
+        bad_inputs.grids[0].rows.truncate(3);
+        let prover = MockProver::run(k, &unsolved, bad_inputs.to_instance_columns().unwrap()).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_private_grid() {
+        let k = 14;
+
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let circuit = PrivateGridWordleCircuit {
+            game: wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42)),
+        };
+        let public_inputs = PrivateGridPublicInputs::from(play::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false).public_inputs());
+        let instance = public_inputs.to_instance_columns().unwrap();
+        assert_eq!(PrivateGridPublicInputs::from_instance_columns(&instance), Ok(public_inputs));
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // The guess count is still checked without the grid
+        let mut bad_instance = instance;
+        bad_instance[2][0] = Fp::from(3);
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_disclosure() {
+        let k = 14;
+
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let circuit = DisclosingWordleCircuit {
+            game: wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42)),
+        };
+
+        // only the starting word
+        let mut reveal = [false; WORD_COUNT];
+        reveal[0] = true;
+        let play = play::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+        let public_inputs = DisclosingPublicInputs::new(play.public_inputs(), &play.words, &reveal);
+        assert_eq!(public_inputs.revealed[..2], [Some(word("audio")), None]);
+
+        let instance = public_inputs.to_instance_columns().unwrap();
+        assert_eq!(DisclosingPublicInputs::from_instance_columns(&instance), Ok(public_inputs));
+        let prover = MockProver::run(k, &circuit, instance.clone()).unwrap();
+        prover.assert_satisfied();
+
+        // A hidden row can't be given out as some other word
+        let mut bad_instance = instance.clone();
+        bad_instance[9][1] = Fp::from(word_to_polyhash("skill").unwrap());
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Nor a revealed one
+        let mut bad_instance = instance;
+        bad_instance[9][0] = Fp::from(word_to_polyhash("adieu").unwrap());
+        let prover = MockProver::run(k, &circuit, bad_instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_feedback() {
+        let k = 14;
+
+        let circuit = |guess: &str| FeedbackCircuit::new(&word(guess), &word("fluff"), Fp::from(42));
+        let instance = |guess: &str, tiles: Vec<Tile>| {
+            FeedbackPublicInputs {
+                commitment: compute_commitment(&word("fluff"), Fp::from(42), DAY),
+                day: DAY,
+                guess: word(guess),
+                tiles,
             }
+            .to_instance_columns()
+            .unwrap()
+        };
+
+        let public_inputs = FeedbackPublicInputs::new(word("hunky"), &word("fluff"), Fp::from(42), DAY);
+        assert_eq!(public_inputs.to_instance_columns().unwrap(), instance("hunky", compute_diff(&word("hunky"), &word("fluff"))));
+        assert_eq!(FeedbackPublicInputs::from_instance_columns(&public_inputs.to_instance_columns().unwrap()), Ok(public_inputs));
+
+        let prover = MockProver::run(k, &circuit("hunky"), instance("hunky", compute_diff(&word("hunky"), &word("fluff")))).unwrap();
+        prover.assert_satisfied();
+
+        // The host can't hide the yellow u
+        let prover = MockProver::run(k, &circuit("hunky"), instance("hunky", vec![Tile::Gray; WORD_LEN])).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Nor answer a different guess than the one asked
+        let prover = MockProver::run(k, &circuit("funky"), instance("hunky", compute_diff(&word("funky"), &word("fluff")))).unwrap();
+        assert!(prover.verify().is_err());
+
+        // Nor commit to the zero word, against which every guess is all gray
+        let mut zero_host = circuit("hunky");
+        zero_host.final_poly_word = Value::known(Fp::zero().into());
+        zero_host.final_chars = [Value::known(Fp::zero().into()); WORD_LEN];
+        let mut zero_instance = instance("hunky", vec![Tile::Gray; WORD_LEN]);
+        zero_instance[0][0] = halo2_gadgets::poseidon::primitives::Hash::<_, P128Pow5T3, ConstantLength<3>, 3, 2>::init()
+            .hash([Fp::zero(), Fp::from(42), Fp::from(DAY)]);
+        let prover = MockProver::run(k, &zero_host, zero_instance).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_wordle_public_inputs() {
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
+        let public_inputs = play::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), true).public_inputs();
+
+        let columns = public_inputs.to_instance_columns().unwrap();
+        assert_eq!(PublicInputs::from_instance_columns(&columns), Ok(public_inputs.clone()));
+
+        let json = serde_json::to_string(&public_inputs).unwrap();
+        assert_eq!(serde_json::from_str::<PublicInputs>(&json).unwrap(), public_inputs);
+
+        // tiles go by name, the grid as a list of rows
+        let grid = Grid::from(vec![vec![Tile::Green, Tile::Yellow, Tile::Gray]]);
+        assert_eq!(serde_json::to_string(&grid).unwrap(), r#"[["green","yellow","gray"]]"#);
+        for tile in [Tile::Gray, Tile::Yellow, Tile::Green] {
+            assert_eq!(Tile::try_from(Fp::from(tile)), Ok(tile));
         }
-        instance.push(yellow);
+        assert!(Tile::try_from(Fp::from(GREEN + 1)).is_err());
 
-        // println!("instance {:?}", instance);
+        // colors past the guess count have no place in the typed form
+        let mut bad_columns = columns.clone();
+        bad_columns[4][words.len() * WORD_LEN] = Fp::from(GREEN);
+        assert!(PublicInputs::<WORD_LEN, WORD_COUNT>::from_instance_columns(&bad_columns).is_err());
+
+        let mut bad_columns = columns;
+        bad_columns[3][0] = Fp::from(2);
+        assert!(PublicInputs::<WORD_LEN, WORD_COUNT>::from_instance_columns(&bad_columns).is_err());
+    }
+
+    #[test]
+    fn test_wordle_more_guesses() {
+        let k = 14;
 
+        // seven guesses only fit in an eight guess game
+        let words = [String::from("lolly"), String::from("skill"), String::from("llama"), String::from("audio"), String::from("bluff"), String::from("stuff"), String::from("fluff")];
+        let circuit = wordle_circuit::<WORD_LEN, 8>(&words, "fluff", Fp::from(42));
+        let instance = wordle_instance::<WORD_LEN, 8>(&words, "fluff", Fp::from(42), false);
         let prover = MockProver::run(k, &circuit, instance).unwrap();
         prover.assert_satisfied();
+    }
+
+    #[test]
+    fn test_wordle_four_letters() {
+        let k = 14;
+
+        let poly_words = |words: &[&str]| -> Vec<u64> { words.iter().map(|word| word_to_polyhash(word).unwrap()).collect() };
+        let dictionary = Dictionary {
+            guesses: poly_words(&["cake", "lake", "lime", "mile"]),
+            answers: poly_words(&["lime", "mile"]),
+        };
+        let new = |words: &[&str], final_word: &str, hard_mode: bool| {
+            let words = words.iter().map(|guess| Word::<4>::new(guess).unwrap()).collect();
+            Play::<4, 5>::new(words, Word::new(final_word).unwrap(), Fp::from(42), DAY, hard_mode, handle_to_field(PLAYER).unwrap(), Fp::from(PLAYER_SECRET), dictionary.clone())
+        };
+
+        let play = new(&["cake", "mile", "lime"], "lime", false).unwrap();
+        assert_eq!(play.grid().rows[1], vec![Tile::Yellow, Tile::Green, Tile::Yellow, Tile::Green]);
+        let prover = MockProver::run(k, &play.circuit(), play.public_inputs().to_instance_columns().unwrap()).unwrap();
+        prover.assert_satisfied();
+
+        let play = new(&["lake", "lime"], "lime", true).unwrap();
+        let prover = MockProver::run(k, &play.circuit(), play.public_inputs().to_instance_columns().unwrap()).unwrap();
+        prover.assert_satisfied();
 
+        // Words are checked against the game's dictionary, guesses and solutions separately
+        assert_eq!(new(&["fume", "lime"], "lime", false), Err(WordleError::UnknownWord(String::from("fume"))));
+        assert_eq!(new(&["cake"], "cake", false), Err(WordleError::UnknownWord(String::from("cake"))));
+    }
+
+    // The mock prover reads back cells the real one never evaluates, so make one real proof.
+    // A small dictionary keeps the keys small enough for a test.
+    #[test]
+    fn test_wordle_real_proof() {
+        use halo2_proofs::pasta::EqAffine;
+        use halo2_proofs::plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, SingleVerifier};
+        use halo2_proofs::poly::commitment::Params;
+        use halo2_proofs::transcript::{Blake2bRead, Blake2bWrite, Challenge255};
+        use rand_core::OsRng;
+
+        let k = 10;
+
+        let words = [String::from("audio"), String::from("fluff")];
+        let mut play = play::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42), false);
+        let poly_words: Vec<u64> = words.iter().map(|word| word_to_polyhash(word).unwrap()).collect();
+        play.dictionary = Dictionary {
+            guesses: poly_words.clone(),
+            answers: poly_words,
+        };
+        let circuit = play.circuit();
+        let instance = play.public_inputs().to_instance_columns().unwrap();
+        let instance: Vec<&[Fp]> = instance.iter().map(|column| &column[..]).collect();
+
+        let params: Params<EqAffine> = Params::new(k);
+        let vk = keygen_vk(&params, &circuit.without_witnesses()).unwrap();
+        let pk = keygen_pk(&params, vk.clone(), &circuit.without_witnesses()).unwrap();
+
+        let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+        create_proof(&params, &pk, &[circuit], &[&instance[..]], OsRng, &mut transcript).unwrap();
+        let proof = transcript.finalize();
+
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
+        assert!(verify_proof(&params, &vk, SingleVerifier::new(&params), &[&instance[..]], &mut transcript).is_ok());
     }
 
     #[cfg(feature = "dev-graph")]
@@ -491,50 +2206,10 @@ mod tests {
             .titled("Wordle Layout", ("sans-serif", 60))
             .unwrap();
 
-        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff"), String::from("fluff"), String::from("fluff")];
-    
-        let mut poly_words: [Value<Assigned<Fp>>; WORD_COUNT] = [Value::known(Fp::from(123).into()); WORD_COUNT];
-        let mut word_chars: [[Value<Assigned<Fp>>; WORD_LEN]; WORD_COUNT] = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-
-        for idx in 0..WORD_COUNT {
-            poly_words[idx] = Value::known(Fp::from(word_to_polyhash(&words[idx].clone())).into());
-            let chars = word_to_chars(&words[idx].clone());
-            for i in 0..WORD_LEN {
-                word_chars[idx][i] = Value::known(Fp::from(chars[i]).into());
-            }
-        }
-
-        let final_word = String::from("fluff");
-        let final_chars = word_to_chars(&final_word);
-
-        let mut word_diffs_green = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-        let mut word_diffs_yellow = [[Value::known(Fp::from(123).into()); WORD_LEN]; WORD_COUNT];
-        for idx in 0..WORD_COUNT {
-            let chars = word_to_chars(&words[idx].clone());
-            for i in 0..WORD_LEN {
-                word_diffs_green[idx][i] = Value::known((Fp::from(chars[i]) - Fp::from(final_chars[i])).into());
-            }
-
-            for i in 0..WORD_LEN {
-                let yellow_diff = {
-                    (0..WORD_LEN).fold(Fp::from(1), |expr, j| {
-                        expr * (Fp::from(chars[i]) - Fp::from(final_chars[j]))
-                    })
-                };
-                word_diffs_yellow[idx][i] = Value::known(Fp::from(yellow_diff).into());
-            }
-        }
-
-        // println!("word_diffs_green {:?}", word_diffs_green);
-        // println!("{:?}", word_diffs_yellow);
+        let words = [String::from("audio"), String::from("hunky"), String::from("funky"), String::from("fluff")];
 
         // Successful cases
-        let circuit = WordleCircuit::<Fp> {
-            poly_words,
-            word_chars,
-            word_diffs_green,
-            word_diffs_yellow,
-        };
+        let circuit = wordle_circuit::<WORD_LEN, WORD_COUNT>(&words, "fluff", Fp::from(42));
         
         halo2_proofs::dev::CircuitLayout::default()
             .render(9, &circuit, &root)
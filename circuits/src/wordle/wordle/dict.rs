@@ -1,3 +1,7 @@
 pub fn get_dict() -> Vec<u32> {
     vec![738547, 742032, 747019, 747397, 756988, 756996, 756998, 757006, 757094, 757220, 757293, 757310, 757456, 757459, 757462, 757485, 757626, 757789, 757890, 757905, 757911, 758196, 758732, 760306, 760311, 760617, 760805, 760863, 763240, 763749, 763792, 766300, 766314, 766315, 766316, 766609, 767239, 768795, 768919, 768947, 769052, 769123, 769200, 769201, 769216, 769303, 769317, 771239, 771251, 771471, 771472, 771477, 772208, 772319, 774127, 774131, 774276, 774319, 774500, 777248, 777648, 777654, 781569, 781820, 783019, 783431, 785177, 785194, 785234, 787223, 787325, 787340, 787626, 788152, 788158, 788430, 788432, 789849, 789862, 791545, 791645, 792371, 792386, 793161, 793415, 793599, 795735, 795750, 795851, 796040, 797417, 797543, 797717, 797721, 798694, 801840, 805886, 806162, 806364, 806422, 806974, 808254, 808350, 808364, 808477, 808554, 809200, 809526, 811608, 812572, 812860, 813005, 815813, 815929, 816045, 816051, 816055, 817515, 817525, 817936, 817979, 817988, 818133, 818211, 820008, 820124, 821438, 822826, 822866, 822907, 823069, 823507, 826443, 826852, 826867, 832011, 832754, 835393, 842266, 844630, 844818, 845484, 854808, 854979, 854997, 857867, 858946, 861711, 863746, 866685, 866757, 866851, 869034, 869207, 870598, 879120, 879223, 879231, 879314, 879386, 879416, 879430, 879488, 879604, 882620, 882635, 882750, 884054, 884157, 884422, 884428, 884438, 884780, 885922, 885926, 885986, 886027, 886137, 886144, 886154, 888246, 888261, 888266, 888546, 888554, 888724, 888985, 890827, 891030, 891044, 891050, 891058, 891142, 893292, 893404, 893417, 893596, 895814, 895829, 896072, 896254, 906631, 906643, 907024, 910173, 910372, 910375, 910388, 915360, 920414, 920597, 930194, 930295, 930309, 930310, 930590, 930600, 931534, 932717, 932921, 937023, 937864, 937878, 938706, 938760, 939754, 942069, 942083, 942345, 942508, 942519, 943114, 943697, 944230, 945447, 947957, 949001, 958904, 959379, 969036, 970557, 979929, 980114, 980176, 983542, 983710, 984859, 1000835, 1000888, 1001182, 1001200, 1001201, 1001203, 1001215, 1001216, 1001255, 1001273, 1001325, 1001337, 1001375, 1001534, 1001679, 1001781, 1002253, 1002737, 1002926, 1003459, 1003476, 1003760, 1004252, 1004260, 1004347, 1004348, 1004626, 1004696, 1004840, 1004884, 1005043, 1005870, 1005877, 1005884, 1006101, 1006111, 1006289, 1006458, 1007566, 1007585, 1007677, 1007711, 1007735, 1007842, 1007929, 1008089, 1008161, 1008244, 1009466, 1009654, 1009929, 1009937, 1010095, 1010191, 1010198, 1010211, 1010321, 1010480, 1010496, 1010499, 1010501, 1010778, 1010919, 1010930, 1011035, 1011046, 1011495, 1011498, 1012699, 1012713, 1012728, 1012758, 1012797, 1012839, 1012975, 1012977, 1013005, 1013018, 1013177, 1013236, 1013638, 1016816, 1016932, 1017016, 1017223, 1017959, 1018006, 1018137, 1018498, 1019346, 1025430, 1025454, 1025749, 1025764, 1025808, 1025938, 1026063, 1026183, 1026301, 1026373, 1026474, 1026567, 1028602, 1028706, 1028953, 1028954, 1028968, 1028969, 1031955, 1031999, 1032003, 1032028, 1032038, 1032042, 1032071, 1032111, 1032125, 1032318, 1032328, 1032332, 1032448, 1032477, 1032512, 1034478, 1035314, 1035720, 1035725, 1036374, 1036376, 1036388, 1037291, 1037306, 1037366, 1037495, 1037580, 1037596, 1037640, 1037943, 1038147, 1038167, 1038415, 1039757, 1042097, 1042555, 1045730, 1049975, 1050149, 1051497, 1051603, 1051699, 1052628, 1052979, 1053285, 1053358, 1054662, 1054771, 1054777, 1054967, 1054977, 1055161, 1055185, 1056507, 1056649, 1056663, 1056674, 1056678, 1056682, 1056745, 1056852, 1058141, 1058229, 1058331, 1058606, 1058867, 1060542, 1060549, 1060550, 1060670, 1060961, 1061122, 1061390, 1061463, 1061695, 1061744, 1064740, 1065581, 1065594, 1065595, 1065696, 1065711, 1065811, 1065827, 1066070, 1066074, 1066123, 1066911, 1067502, 1070201, 1088835, 1098438, 1098554, 1098611, 1098888, 1099075, 1099090, 1101750, 1101866, 1102251, 1102252, 1102257, 1103439, 1104498, 1104513, 1105117, 1105487, 1105521, 1105633, 1105638, 1109425, 1110241, 1110255, 1110571, 1110662, 1111002, 1111015, 1111118, 1111314, 1111334, 1111498, 1111579, 1111595, 1112807, 1113092, 1113648, 1113764, 1113938, 1114474, 1114488, 1114698, 1139589, 1139603, 1147183, 1147462, 1147506, 1147665, 1148013, 1148418, 1148955, 1149047, 1149260, 1149434, 1149794, 1150100, 1150178, 1150521, 1150522, 1150529, 1150535, 1150536, 1150576, 1150608, 1150609, 1150687, 1150752, 1150895, 1150899, 1150953, 1151015, 1151073, 1151087, 1151088, 1152211, 1152213, 1152443, 1152523, 1152617, 1152619, 1152625, 1152784, 1152798, 1153060, 1153900, 1154009, 1154180, 1154219, 1154307, 1154408, 1154411, 1155683, 1156524, 1156539, 1157365, 1157379, 1157381, 1157489, 1157669, 1158105, 1158686, 1158957, 1159131, 1159163, 1159276, 1159454, 1159787, 1159898, 1161458, 1161469, 1161475, 1161586, 1161701, 1161879, 1161882, 1162411, 1162426, 1162432, 1162542, 1162711, 1163144, 1163260, 1163367, 1163383, 1163679, 1164181, 1164340, 1164826, 1164935, 1165239, 1167675, 1171920, 1173644, 1173650, 1173823, 1174300, 1177549, 1177559, 1177564, 1177565, 1178362, 1180072, 1180086, 1180091, 1180367, 1180377, 1184287, 1184291, 1184392, 1184394, 1184408, 1184665, 1186689, 1186693, 1186705, 1186815, 1186816, 1186822, 1187106, 1187655, 1187771, 1188115, 1188856, 1190069, 1190289, 1192046, 1196385, 1196623, 1202842, 1202998, 1203032, 1205201, 1206037, 1206042, 1206448, 1207782, 1208000, 1208014, 1208036, 1208072, 1208078, 1208087, 1208107, 1208165, 1210461, 1210476, 1211926, 1211928, 1212145, 1212770, 1222847, 1223090, 1223095, 1225500, 1225589, 1225964, 1229590, 1229806, 1229986, 1229996, 1230656, 1231388, 1231823, 1231829, 1234622, 1234629, 1234635, 1234636, 1234742, 1234752, 1234852, 1234868, 1235210, 1236724, 1239909, 1244953, 1245033, 1245106, 1245251, 1248411, 1248469, 1248585, 1248586, 1249774, 1251451, 1251819, 1251857, 1251964, 1251974, 1252167, 1256719, 1257140, 1265623, 1269350, 1269393, 1269595, 1269596, 1269605, 1269628, 1269664, 1269712, 1269813, 1271959, 1272590, 1273028, 1273895, 1276210, 1279708, 1280151, 1280165, 1281167, 1281210, 1281376, 1297189, 1300227, 1300539, 1300553, 1300599, 1300634, 1300635, 1300771, 1302858, 1302873, 1303593, 1303709, 1305497, 1305643, 1305657, 1318098, 1318573, 1321575, 1323420, 1325000, 1330047, 1332308, 1332410, 1342661, 1349085, 1349095, 1349375, 1351936, 1354274, 1354363, 1354435, 1354595, 1354598, 1359384, 1359583, 1359592, 1359603, 1362648, 1362802, 1362816, 1439941, 1440159, 1440681, 1440790, 1440797, 1440953, 1441083, 1441254, 1441261, 1441562, 1441576, 1441586, 1441707, 1441725, 1441812, 1441923, 1442456, 1442523, 1442688, 1443523, 1444190, 1444196, 1444596, 1444995, 1445066, 1445089, 1445104, 1446278, 1446307, 1446308, 1446887, 1447056, 1447072, 1447108, 1447119, 1447275, 1447279, 1447404, 1447884, 1447892, 1447989, 1448351, 1448361, 1448365, 1448366, 1448725, 1449091, 1449178, 1449184, 1449192, 1449206, 1449207, 1449381, 1449387, 1449410, 1449416, 1449439, 1449445, 1449493, 1449595, 1449632, 1449666, 1449671, 1449951, 1450765, 1450766, 1450827, 1450831, 1450842, 1450849, 1450860, 1450866, 1450874, 1450889, 1450947, 1450987, 1451030, 1451063, 1451150, 1451324, 1451326, 1451330, 1451451, 1453035, 1454152, 1454166, 1454172, 1454177, 1454210, 1454220, 1454224, 1454230, 1454238, 1454252, 1454253, 1454272, 1454282, 1454297, 1454353, 1454427, 1454433, 1454485, 1454491, 1454514, 1454520, 1454538, 1454572, 1454612, 1454616, 1454626, 1454636, 1454819, 1454971, 1454973, 1455079, 1455089, 1455093, 1455094, 1455177, 1455194, 1455201, 1455203, 1455205, 1455210, 1455268, 1455379, 1455486, 1455490, 1455496, 1455506, 1455511, 1455515, 1455519, 1455525, 1455529, 1455866, 1455920, 1455935, 1456008, 1456022, 1456043, 1456220, 1456352, 1456370, 1456372, 1456376, 1456747, 1456950, 1456971, 1457153, 1457728, 1458429, 1458435, 1458632, 1458661, 1458719, 1458835, 1458899, 1460125, 1460139, 1460140, 1460329, 1460432, 1460575, 1460864, 1461267, 1537443, 1537483, 1537489, 1537686, 1537692, 1537715, 1537744, 1537750, 1537769, 1537773, 1537779, 1537874, 1537875, 1537889, 1537919, 1537936, 1537947, 1537953, 1537976, 1537977, 1537981, 1538640, 1539075, 1539354, 1539368, 1539904, 1540028, 1540035, 1540039, 1540145, 1540601, 1540807, 1540837, 1540905, 1540911, 1541195, 1541253, 1541259, 1541311, 1541834, 1541995, 1542427, 1542437, 1542441, 1542443, 1542552, 1542559, 1542669, 1542849, 1543016, 1543017, 1544284, 1544304, 1544489, 1544501, 1545795, 1546636, 1546646, 1546653, 1546694, 1546749, 1546803, 1546865, 1546952, 1546966, 1546972, 1547048, 1547057, 1547198, 1547473, 1547488, 1547725, 1548053, 1548376, 1548416, 1548422, 1548445, 1548446, 1548485, 1548561, 1548692, 1548696, 1548712, 1548876, 1548880, 1548886, 1550012, 1551699, 1551809, 1551810, 1551867, 1551979, 1551983, 1552027, 1552041, 1552082, 1552192, 1552233, 1552382, 1552535, 1552538, 1552636, 1552650, 1552651, 1552767, 1552934, 1552941, 1553075, 1553085, 1553375, 1553476, 1553484, 1553491, 1553578, 1553592, 1553776, 1553908, 1553932, 1555166, 1555172, 1555462, 1555623, 1555672, 1556015, 1556118, 1558530, 1558537, 1558646, 1559152, 1610795, 1610814, 1610928, 1611114, 1614246, 1622744, 1627742, 1627934, 1634999, 1635261, 1635277, 1635822, 1635909, 1636098, 1636698, 1636747, 1636750, 1637568, 1637576, 1637590, 1637591, 1637592, 1637707, 1637876, 1638620, 1638809, 1639298, 1639302, 1639308, 1639374, 1639984, 1640172, 1640178, 1640183, 1640202, 1640323, 1640404, 1640405, 1642929, 1643463, 1643477, 1643478, 1643580, 1644228, 1644238, 1644304, 1644319, 1644363, 1644383, 1644493, 1644522, 1644528, 1645033, 1645044, 1645069, 1645878, 1645962, 1645972, 1646000, 1646001, 1646045, 1646055, 1646059, 1646065, 1646118, 1646175, 1646436, 1646900, 1647060, 1647104, 1647259, 1647668, 1647958, 1649296, 1649336, 1649539, 1649554, 1649568, 1649655, 1649742, 1649757, 1649777, 1649789, 1650206, 1650380, 1650490, 1650491, 1650978, 1651046, 1651047, 1651337, 1651339, 1651459, 1651468, 1651482, 1651488, 1652827, 1653228, 1656093, 1656698, 1656708, 1708148, 1708169, 1708192, 1708206, 1708212, 1708234, 1708235, 1708251, 1708293, 1708322, 1708346, 1708453, 1708467, 1708481, 1708488, 1708598, 1708613, 1708627, 1708630, 1708642, 1708656, 1708670, 1708671, 1708684, 1708752, 1708757, 1708815, 1708830, 1711475, 1711482, 1711484, 1711512, 1711530, 1711584, 1711596, 1711599, 1711845, 1711861, 1711977, 1712005, 1712006, 1712034, 1712179, 1715192, 1715201, 1715209, 1715212, 1715214, 1715216, 1715224, 1715230, 1715282, 1715369, 1715370, 1715384, 1715398, 1715405, 1715442, 1719894, 1719922, 1719943, 1719951, 1720067, 1720169, 1720255, 1720284, 1720291, 1720293, 1720296, 1720372, 1720444, 1720526, 1720531, 1720537, 1724968, 1725012, 1725026, 1725032, 1725040, 1725054, 1725055, 1725056, 1725061, 1725071, 1725156, 1725273, 1725308, 1725317, 1725415, 1725432, 1725433, 1725450, 1728724, 1781315, 1781576, 1781764, 1781779, 1781780, 1781809, 1781837, 1782111, 1782119, 1782127, 1782162, 1782526, 1782533, 1783008, 1783012, 1783016, 1783157, 1783258, 1783910, 1783925, 1783969, 1784002, 1784114, 1785085, 1785201, 1785217, 1785632, 1785636, 1786327, 1786454, 1786512, 1786550, 1786637, 1786898, 1786912, 1787271, 1787579, 1788333, 1788379, 1788383, 1788551, 1789797, 1789801, 1789812, 1790102, 1790536, 1790537, 1790624, 1790653, 1790774, 1790856, 1790943, 1791088, 1791117, 1791378, 1791393, 1791403, 1791407, 1792263, 1792306, 1792320, 1792334, 1792335, 1792341, 1792389, 1792393, 1792437, 1792509, 1792582, 1792602, 1792799, 1792926, 1792930, 1793050, 1793066, 1793089, 1793095, 1793153, 1793161, 1793211, 1793240, 1793263, 1793350, 1793356, 1793379, 1793408, 1793414, 1793425, 1793437, 1793538, 1793553, 1793568, 1793583, 1793600, 1793611, 1793617, 1793771, 1793791, 1794342, 1795575, 1795576, 1795583, 1795588, 1795656, 1795670, 1795684, 1795685, 1795692, 1795698, 1795699, 1795753, 1795799, 1795873, 1795931, 1795942, 1795946, 1795984, 1796134, 1796140, 1796141, 1796642, 1796714, 1796720, 1796824, 1796825, 1796952, 1796999, 1797265, 1797312, 1797374, 1797381, 1797474, 1797802, 1797816, 1797822, 1798266, 1798269, 1798396, 1798411, 1798426, 1798468, 1798483, 1798584, 1798587, 1798594, 1798614, 1798634, 1798657, 1799164, 1799789, 1799889, 1799897, 1799903, 1799904, 1799905, 1800006, 1800107, 1800151, 1800281, 1800296, 1800730, 1800740, 1800744, 1800745, 1800930, 1801186, 1801469, 1801472, 1801571, 1801615, 1801644, 1801771, 1801876, 1801998, 1802717, 1854443, 1854497, 1854500, 1854503, 1854512, 1854540, 1854569, 1854627, 1854670, 1854678, 1854680, 1854729, 1854743, 1854749, 1854787, 1854815, 1854816, 1854822, 1854830, 1854831, 1854964, 1854975, 1854976, 1855004, 1855044, 1855048, 1855052, 1855058, 1855084, 1855086, 1855091, 1855126, 1855149, 1855160, 1855164, 1857802, 1857809, 1857811, 1857890, 1857904, 1857918, 1857927, 1857932, 1857933, 1858034, 1858049, 1858151, 1858194, 1858195, 1858296, 1858310, 1858412, 1858455, 1858513, 1861180, 1861196, 1861231, 1861254, 1861284, 1861296, 1861297, 1861355, 1861461, 1861471, 1861493, 1861529, 1861544, 1861546, 1861550, 1861558, 1861564, 1861587, 1861689, 1861695, 1861703, 1861721, 1861732, 1861733, 1861892, 1866212, 1866274, 1866277, 1866314, 1866390, 1866401, 1866452, 1866503, 1866561, 1866571, 1866588, 1866589, 1866618, 1866625, 1866626, 1866627, 1866633, 1866735, 1866755, 1866767, 1866860, 1866865, 1871436, 1871500, 1871506, 1871578, 1871607, 1871638, 1871651, 1871784, 1871787, 1871796, 1871810, 1871824, 1928171, 1928331, 1928454, 1928461, 1928472, 1928476, 1928496, 1929041, 1929507, 1929588, 1929592, 1929594, 1930143, 1930236, 1930303, 1930375, 1930549, 1931952, 1931956, 1931960, 1931966, 1931970, 1931976, 1932231, 1932382, 1932846, 1932971, 1933826, 1934000, 1934638, 1934652, 1934668, 1934871, 1936146, 1936900, 1937031, 1937051, 1937161, 1937167, 1937172, 1937190, 1937196, 1937379, 1937737, 1937857, 1938136, 1938147, 1938153, 1938553, 1938597, 1938600, 1938607, 1938626, 1938629, 1938640, 1938641, 1938642, 1938646, 1938727, 1938733, 1938767, 1938800, 1938820, 1938839, 1938843, 1938930, 1938936, 1939104, 1939110, 1939231, 1940090, 1940676, 1941912, 1941917, 1941946, 1942004, 1942034, 1942052, 1942080, 1942091, 1942144, 1942189, 1942193, 1942207, 1942236, 1942242, 1942294, 1942295, 1942319, 1942352, 1942363, 1942406, 1942410, 1942416, 1942421, 1942425, 1942440, 1942793, 1942859, 1942874, 1942967, 1943048, 1943054, 1943282, 1943299, 1943309, 1943315, 1943646, 1943711, 1943715, 1943904, 1943994, 1944136, 1944150, 1944156, 1944180, 1944288, 1947363, 1947919, 1949376, 1976757, 1977113, 2027800, 2027815, 2033687, 2033702, 2034431, 2039589, 2039792, 2040826, 2041271, 2043684, 2147222, 2147955, 2147962, 2147997, 2148077, 2148189, 2148267, 2148351, 2148352, 2148441, 2148799, 2148803, 2148992, 2149093, 2149099, 2149344, 2149737, 2149746, 2149760, 2149761, 2149804, 2149824, 2149862, 2149876, 2150123, 2150525, 2150993, 2151442, 2151471, 2152268, 2152282, 2152283, 2152289, 2152574, 2153418, 2153936, 2154226, 2154327, 2154337, 2155091, 2155265, 2155632, 2155647, 2155653, 2156517, 2156589, 2156591, 2156609, 2156662, 2156673, 2156691, 2156720, 2156726, 2156778, 2156789, 2156807, 2156967, 2157073, 2157208, 2157213, 2157322, 2157325, 2157329, 2157445, 2157619, 2157638, 2157644, 2157648, 2157654, 2157793, 2158047, 2158147, 2158155, 2158159, 2158169, 2158170, 2158228, 2158271, 2158413, 2158431, 2158437, 2158446, 2158455, 2158572, 2158577, 2158601, 2158605, 2158611, 2159736, 2159837, 2159851, 2159852, 2159857, 2159939, 2159975, 2160041, 2160137, 2160142, 2160143, 2160219, 2160309, 2160317, 2161415, 2161419, 2161443, 2161447, 2161453, 2161495, 2161505, 2161511, 2161519, 2161533, 2161534, 2161535, 2161539, 2161588, 2161708, 2161723, 2161737, 2161795, 2161801, 2161807, 2161817, 2161818, 2161819, 2161843, 2161853, 2161911, 2161917, 2161926, 2161951, 2161955, 2161969, 2162013, 2162033, 2162259, 2162313, 2162360, 2162375, 2162549, 2162555, 2162796, 2162810, 2162839, 2163147, 2163215, 2163216, 2163657, 2164010, 2164231, 2164245, 2164252, 2164260, 2164289, 2164376, 2164424, 2164445, 2164449, 2164782, 2164883, 2164891, 2164897, 2164898, 2165000, 2165007, 2165724, 2165913, 2166865, 2245185, 2245388, 2245735, 2246352, 2246933, 2247199, 2247301, 2247315, 2247316, 2247432, 2251416, 2251714, 2251724, 2254027, 2254229, 2254237, 2254243, 2254247, 2254328, 2254479, 2256118, 2256157, 2256161, 2256163, 2256937, 2257408, 2259022, 2259075, 2259090, 2259134, 2259188, 2259190, 2259337, 2259364, 2259380, 2259525, 2259531, 2260323, 2260348, 2260356, 2260772, 2261345, 2266297, 2317888, 2317894, 2317898, 2317927, 2317931, 2317975, 2317976, 2317990, 2318071, 2318075, 2318076, 2318155, 2318163, 2318189, 2318192, 2318203, 2318209, 2318213, 2318222, 2318250, 2318265, 2318279, 2318280, 2318319, 2318322, 2318323, 2318329, 2318331, 2318336, 2318337, 2318338, 2318343, 2318352, 2318360, 2318395, 2318439, 2318453, 2318474, 2318482, 2318522, 2318540, 2321205, 2321209, 2321258, 2321316, 2321321, 2321323, 2321353, 2321480, 2321509, 2321524, 2321552, 2321556, 2321687, 2321702, 2321730, 2321731, 2321748, 2321823, 2321846, 2321852, 2324568, 2324572, 2324601, 2324612, 2324619, 2324622, 2324626, 2324630, 2324645, 2324675, 2324681, 2324862, 2324876, 2324877, 2324881, 2324884, 2324903, 2324906, 2324916, 2324917, 2324931, 2324935, 2324937, 2324941, 2324945, 2324949, 2325007, 2325057, 2325058, 2325059, 2325061, 2325062, 2325064, 2325066, 2325067, 2325123, 2325167, 2325181, 2325187, 2325304, 2329668, 2329672, 2329676, 2329691, 2329792, 2329843, 2329849, 2329894, 2329904, 2329914, 2329919, 2329927, 2329933, 2329963, 2329995, 2330011, 2330016, 2330018, 2330019, 2330053, 2330096, 2330097, 2330126, 2330151, 2330170, 2330199, 2330203, 2330248, 2330256, 2334693, 2334714, 2334791, 2334796, 2334838, 2335009, 2335012, 2335033, 2335150, 2335152, 2335156, 2335172, 2335201, 2335215, 2338333, 2338362, 2338390, 2343473, 2344857, 2344871, 2344872, 2345916, 2347278, 2347459, 2351698, 2351803, 2352324, 2352446, 2353213, 2353225, 2353282, 2353630, 2354384, 2355273, 2356570, 2356588, 2356646, 2356849, 2357013, 2357425, 2357899, 2357922, 2358205, 2358313, 2358327, 2358328, 2360010, 2360011, 2360110, 2360112, 2360119, 2360509, 2415447, 2415450, 2415473, 2415487, 2415516, 2415574, 2415626, 2415734, 2415745, 2415748, 2415765, 2415769, 2415777, 2415835, 2415836, 2415889, 2415894, 2415899, 2415911, 2415919, 2415922, 2415923, 2415951, 2415981, 2415995, 2415999, 2416038, 2416096, 2418759, 2418763, 2418765, 2418814, 2418872, 2418877, 2418909, 2418910, 2418938, 2418988, 2419112, 2419185, 2419200, 2419249, 2419359, 2419402, 2422178, 2422229, 2422244, 2422260, 2422274, 2422459, 2422462, 2422491, 2422493, 2422497, 2422506, 2422549, 2422563, 2422564, 2422679, 2427166, 2427168, 2427224, 2427261, 2427306, 2427348, 2427450, 2427505, 2427519, 2427537, 2427543, 2427551, 2427577, 2427581, 2427609, 2427682, 2427711, 2427714, 2427725, 2427739, 2427753, 2427754, 2427755, 2427769, 2427807, 2427812, 2427856, 2427870, 2427885, 2432249, 2432270, 2432321, 2432336, 2432342, 2432565, 2432585, 2432589, 2436005, 2470975, 2488614, 2488626, 2488660, 2488868, 2488886, 2488892, 2489003, 2489043, 2489090, 2489104, 2489108, 2489118, 2489437, 2489443, 2489622, 2489713, 2489883, 2490115, 2490249, 2490297, 2490303, 2490539, 2490545, 2490637, 2490655, 2491090, 2491190, 2491191, 2491201, 2491205, 2491206, 2491211, 2491491, 2492018, 2492917, 2493831, 2494014, 2494179, 2494437, 2494565, 2494846, 2494848, 2494860, 2495440, 2495464, 2495614, 2495672, 2495788, 2495846, 2497078, 2497093, 2497818, 2497853, 2497905, 2497919, 2497934, 2497940, 2498034, 2498045, 2498137, 2498143, 2498212, 2498219, 2498223, 2498369, 2498525, 2498645, 2498652, 2498659, 2498674, 2498678, 2498684, 2498688, 2498694, 2498774, 2498775, 2498776, 2498810, 2498875, 2498896, 2498989, 2499003, 2499007, 2499013, 2499090, 2499094, 2499095, 2499196, 2499239, 2499547, 2499583, 2499601, 2499616, 2499622, 2499645, 2499656, 2499660, 2499670, 2499714, 2499716, 2499727, 2499790, 2499796, 2499863, 2499877, 2500037, 2500047, 2500080, 2500105, 2500388, 2500442, 2500443, 2500456, 2500463, 2500486, 2500631, 2500637, 2500660, 2500666, 2500672, 2500689, 2500695, 2500718, 2500776, 2500777, 2500864, 2500892, 2501052, 2501175, 2501188, 2501283, 2501293, 2501297, 2501298, 2501623, 2501657, 2501690, 2501710, 2502593, 2502857, 2502858, 2502879, 2502899, 2502951, 2502965, 2502979, 2502980, 2502986, 2503028, 2503078, 2503154, 2503160, 2503212, 2503231, 2503237, 2503241, 2503243, 2503247, 2503299, 2503372, 2503382, 2503805, 2503806, 2503821, 2503822, 2503827, 2503923, 2504238, 2504242, 2504256, 2504541, 2504647, 2504662, 2504749, 2505079, 2505097, 2505434, 2505460, 2505550, 2505691, 2505765, 2505808, 2505822, 2505863, 2505865, 2505866, 2505880, 2505881, 2505920, 2505927, 2506329, 2506339, 2506343, 2506344, 2506345, 2506350, 2506455, 2507062, 2507064, 2507170, 2507184, 2507359, 2507388, 2507504, 2507568, 2507896, 2507903, 2508011, 2508026, 2508125, 2508753, 2508852, 2508866, 2509076, 2509188, 2509693, 2509703, 2509708, 2509714, 2509810, 2561727, 2561763, 2561784, 2561880, 2561908, 2561950, 2561954, 2562010, 2562068, 2562079, 2562082, 2562097, 2562103, 2562111, 2562155, 2562169, 2562175, 2562213, 2562245, 2562256, 2562271, 2562329, 2562365, 2562372, 2562430, 2562445, 2562465, 2565090, 2565092, 2565181, 2565185, 2565199, 2565206, 2565207, 2565211, 2565214, 2565432, 2565446, 2565457, 2565519, 2565533, 2565534, 2565539, 2565620, 2565621, 2565722, 2565736, 2568462, 2568491, 2568512, 2568563, 2568577, 2568578, 2568796, 2568807, 2568810, 2568825, 2568868, 2568883, 2568897, 2568970, 2568981, 2569002, 2569013, 2573500, 2573556, 2573558, 2573566, 2573654, 2573682, 2573839, 2573842, 2573871, 2573877, 2573885, 2573891, 2573906, 2573907, 2573909, 2573943, 2573987, 2574030, 2574031, 2574085, 2574089, 2574131, 2574141, 2574146, 2574219, 2578604, 2578627, 2578637, 2578641, 2578647, 2578663, 2578670, 2578671, 2578700, 2578885, 2578899, 2578923, 2578959, 2579029, 2579062, 2579065, 2579077, 2579082, 2579149, 2580776, 2582033, 2582354, 2614239, 2635777, 2635841, 2635843, 2635857, 2635858, 2635958, 2635975, 2637517, 2639247, 2639251, 2641774, 2641994, 2642140, 2642180, 2643427, 2644199, 2644269, 2644273, 2644471, 2644477, 2644500, 2644569, 2644693, 2644703, 2644709, 2645093, 2645220, 2645927, 2645940, 2646067, 2646385, 2647625, 2647733, 2647933, 2647957, 2649199, 2649227, 2649245, 2649285, 2649291, 2649299, 2649313, 2649314, 2649315, 2649343, 2649412, 2649416, 2649426, 2649507, 2649517, 2649523, 2649575, 2649581, 2649691, 2649697, 2649706, 2649710, 2649721, 2649793, 2649813, 2650139, 2650248, 2650329, 2650474, 2650480, 2650557, 2650613, 2650927, 2650995, 2650996, 2651002, 2651098, 2651107, 2651112, 2651427, 2651437, 2651457, 2652664, 2656042, 2699705, 2732830, 2732834, 2733413, 2734124, 2734139, 2734444, 2734454, 2735095, 2741945, 2742535, 2742548, 2742549, 2742665, 2742948, 2743606, 2748146, 2748552, 2748837, 2757339, 2854721, 2855254, 2856200, 2856269, 2856374, 2856914, 2856925, 2857018, 2857331, 2858752, 2858758, 2859604, 2859628, 2859854, 2860608, 2861416, 2861455, 2861493, 2861508, 2861629, 2861658, 2862927, 2863754, 2863769, 2863885, 2863958, 2863978, 2864204, 2864489, 2864493, 2864610, 2864828, 2864871, 2864929, 2864935, 2865379, 2865399, 2865428, 2865509, 2865563, 2865625, 2865718, 2865886, 2868686, 2868728, 2868763, 2868800, 2868814, 2868815, 2868855, 2868873, 2868915, 2868931, 2868989, 2868995, 2869076, 2869178, 2869250, 2869414, 2869733, 2869749, 2870374, 2870482, 2870496, 2870497, 2870787, 2870928, 2870955, 2871237, 2871251, 2871257, 2871309, 2871542, 2871600, 2871715, 2871773, 2872174, 2872296, 2872893, 2872991, 2873005, 2873015, 2873194, 2873281, 2873455, 2874581, 2874633, 2874964, 2875528, 2875542, 2875543, 2952045, 2952189, 2952277, 2952278, 2952335, 2952437, 2952446, 2952451, 2952457, 2952469, 2952498, 2952553, 2952596, 2952602, 2952787, 2952798, 2952834, 2952908, 2952915, 2953032, 2953350, 2953364, 2953367, 2953378, 2953379, 2953380, 2953481, 2953625, 2953627, 2953633, 2953635, 2953646, 2953926, 2953930, 2954045, 2954046, 2954052, 2954139, 2954474, 2955409, 2955415, 2955647, 2955670, 2955699, 2955757, 2955801, 2955815, 2955873, 2955917, 2955931, 2956164, 2956278, 2956304, 2956395, 2956557, 2957004, 2957578, 2957584, 2958730, 2958773, 2958837, 2958855, 2959005, 2959202, 2959209, 2959243, 2960469, 2960484, 2960654, 2961215, 2961310, 2961325, 2961354, 2961355, 2961441, 2961528, 2961534, 2961615, 2961633, 2961742, 2961760, 2961804, 2962045, 2962166, 2962266, 2962283, 2962439, 2962446, 2962451, 2962456, 2962486, 2962629, 2962890, 2962897, 2962938, 2963007, 2963008, 2963117, 2963123, 2963399, 2963442, 2964405, 2964980, 2965113, 2966256, 2966261, 2966290, 2966356, 2966371, 2966475, 2966585, 2966603, 2966632, 2966638, 2966661, 2966744, 2966754, 2966795, 2966864, 2967217, 2967289, 2967328, 2967386, 2967604, 2968052, 2968348, 2968822, 2969619, 2969728, 2969844, 2969851, 2970020, 2970025, 2970026, 2970455, 2970459, 2970465, 2970561, 2971417, 2971519, 3025136, 3025415, 3025444, 3032142, 3036918, 3037204, 3037211, 3037218, 3037440, 3037537, 3042486, 3049573, 3049833, 3049877, 3050013, 3050235, 3050384, 3051297, 3051311, 3051312, 3051318, 3051400, 3051486, 3051492, 3051603, 3051729, 3051747, 3051753, 3052130, 3052255, 3052443, 3052560, 3052907, 3053197, 3053241, 3053429, 3053864, 3054764, 3054793, 3057924, 3058025, 3058039, 3058040, 3058046, 3058848, 3059074, 3059084, 3059090, 3059631, 3059721, 3059722, 3059931, 3060041, 3060446, 3060548, 3060562, 3060563, 3060607, 3060617, 3060621, 3060627, 3060663, 3060737, 3060743, 3060806, 3060853, 3060998, 3061361, 3061607, 3061821, 3062570, 3062647, 3063805, 3063926, 3063971, 3064087, 3064101, 3064130, 3064362, 3064368, 3064652, 3064700, 3064706, 3064710, 3064861, 3064942, 3064986, 3065486, 3065493, 3065540, 3065594, 3065609, 3066021, 3066040, 3066044, 3066050, 3066224, 3067170, 3067175, 3067276, 3067290, 3067291, 3067407, 3067534, 3067581, 3067582, 3067790, 3068011, 3069075, 3069090, 3069698, 3070650, 3071270, 3081003, 3081008, 3195877, 3196399, 3196724, 3196805, 3196907, 3196990, 3197164, 3197178, 3197734, 3197820, 3197936, 3198110, 3198464, 3198531, 3198551, 3198777, 3199502, 3199705, 3199735, 3199752, 3200198, 3200889, 3201010, 3201016, 3201064, 3201074, 3201112, 3201224, 3202427, 3202896, 3202901, 3202941, 3203127, 3203823, 3205143, 3205147, 3205200, 3205215, 3205313, 3205418, 3205424, 3205429, 3205504, 3205505, 3205650, 3205933, 3206041, 3206056, 3206156, 3206770, 3206781, 3206883, 3206896, 3206937, 3206955, 3207067, 3207140, 3207144, 3207164, 3207186, 3207309, 3207362, 3207651, 3207666, 3207715, 3207912, 3207927, 3207941, 3207947, 3207970, 3207976, 3207981, 3208110, 3208115, 3208353, 3208463, 3208564, 3208578, 3208579, 3208585, 3210130, 3210156, 3210174, 3210247, 3210261, 3210361, 3210377, 3210435, 3210441, 3210493, 3210499, 3210580, 3210638, 3210649, 3210653, 3210696, 3210702, 3210976, 3210986, 3211087, 3211091, 3211101, 3211102, 3211171, 3211820, 3211928, 3211942, 3211943, 3212384, 3212667, 3212698, 3212712, 3212726, 3212831, 3212958, 3212969, 3212998, 3213016, 3213103, 3213143, 3213176, 3213219, 3213610, 3213620, 3213624, 3213625, 3213727, 3214349, 3214437, 3214443, 3214451, 3214459, 3214465, 3214568, 3214655, 3214669, 3214675, 3214709, 3214727, 3214733, 3214785, 3214849, 3214858, 3214901, 3215292, 3215307, 3215409, 3216143, 3216357, 3216974, 3216984, 3216988, 3216989, 3269044, 3269065, 3269069, 3269147, 3269161, 3269189, 3269240, 3269242, 3269291, 3269345, 3269363, 3269384, 3269393, 3269436, 3269450, 3269566, 3269610, 3269646, 3269648, 3269653, 3269711, 3272364, 3272373, 3272378, 3272429, 3272480, 3272494, 3272495, 3272553, 3272669, 3272757, 3272858, 3272901, 3272902, 3273075, 3275772, 3275787, 3275844, 3275858, 3275859, 3275889, 3276055, 3276068, 3276112, 3276178, 3276179, 3276338, 3281006, 3281014, 3281022, 3281065, 3281094, 3281101, 3281123, 3281152, 3281172, 3281178, 3281183, 3281187, 3281188, 3281192, 3281224, 3281225, 3281311, 3281361, 3281384, 3281422, 3281427, 3285864, 3286009, 3286052, 3286183, 3286204, 3286256, 3286343, 3286363, 3286508, 3289184, 3289199, 3289314, 3289524, 3305203, 3305522, 3342269, 3342501, 3342559, 3342675, 3343048, 3343857, 3343865, 3343980, 3344073, 3344154, 3344160, 3344415, 3344798, 3344806, 3344821, 3345865, 3346097, 3346098, 3346532, 3346967, 3349275, 3349461, 3350592, 3350693, 3350708, 3350864, 3351477, 3351549, 3351647, 3351752, 3351758, 3351941, 3352274, 3352299, 3352303, 3352546, 3352570, 3352628, 3352709, 3352715, 3353109, 3353159, 3353162, 3353231, 3353289, 3353295, 3353405, 3353488, 3353498, 3353626, 3353666, 3354294, 3354300, 3354898, 3354912, 3354913, 3355102, 3355122, 3355238, 3356472, 3356479, 3356580, 3356595, 3356659, 3356856, 3356869, 3356885, 3356891, 3356954, 3356972, 3356978, 3357002, 3357053, 3357194, 3357610, 3357616, 3357871, 3357877, 3358208, 3359960, 3361641, 3390953, 3391265, 3391273, 3391308, 3391325, 3391440, 3391534, 3394423, 3394636, 3394644, 3397993, 3398051, 3439825, 3443595, 3446831, 3448249, 3448264, 3448270, 3448549, 3450780, 3450787, 3470420, 3566844, 3567034, 3567208, 3571035, 3571050, 3572717, 3576067, 3576081, 3576299, 3576305, 3576357, 3576358, 3576503, 3576520, 3576922, 3576930, 3576936, 3576937, 3577126, 3577372, 3577773, 3577777, 3577851, 3579445, 3579460, 3587014, 3587030, 3598223, 3598229, 3598244, 3610548, 3616449, 3616464, 3616754, 3619713, 3625338, 3638544, 3639997, 3640011, 3640012, 3641637, 3641729, 3641883, 3642129, 3651714, 3651729, 3667041, 3670700, 3673754, 3677011, 3677277, 3687934, 3708104, 3711874, 3711904, 3713062, 3713164, 3713178, 3718109, 3722431, 3739499, 3759433, 3759724, 3762045, 3762204, 3765306, 3765606, 3766133, 3772042, 3784607, 3787995, 3812666, 3813983, 3830189, 3830324, 3830339, 3830499, 3831213, 3831837, 3832600, 3832712, 3833385, 3833506, 3833664, 3834268, 3834394, 3836675, 3836763, 3837068, 3837227, 3840165, 3841896, 3841916, 3841963, 3842157, 3842171, 3842679, 3845327, 3846855, 3847319, 3847618, 3847734, 3847738, 3847739, 3854409, 3854576, 3855191, 3855198, 3855293, 3855307, 3855586, 3855602, 3855603, 3855772, 3856135, 3857830, 3858077, 3858196, 3858214, 3858295, 3861137, 3861572, 3861630, 3864443, 3864558, 3864560, 3864563, 3865139, 3866376, 3866490, 3866662, 3866720, 3867517, 3867523, 3871476, 3871650, 3874608, 3874622, 3878799, 3879227, 3879277, 3881364, 3881378, 3881383, 3881673, 3881829, 3882434, 3882458, 3882742, 3883182, 3885452, 3886721, 3888224, 3888952, 3890067, 3890243, 3890794, 3890833, 3891001, 3891152, 3893436, 3893998, 3894174, 3894444, 3894834, 3894933, 3895218, 3896039, 3896048, 3896797, 3896813, 3900455, 3917745, 3918494, 3927577, 3930998, 3933394, 3933405, 3933796, 3933810, 3934304, 3939339, 3939365, 3939728, 3939965, 3942047, 3968720, 3968843, 3968944, 3968956, 3976804, 3977351, 3979719, 3980269, 3981743, 3981748, 3981749, 3982763, 3983064, 3983074, 3983082, 3983389, 3987330, 3987345, 3988143, 3988578, 3990694, 3990998, 3991550, 3993175, 3993286, 3993552, 3993725, 3994068, 3994182, 4002366, 4002774, 4007718, 4009094, 4009210, 4011734, 4015829, 4015939, 4016779, 4017054, 4017067, 4017153, 4025234, 4025495, 4025625, 4028815, 4030954, 4031076, 4031177, 4031324, 4031367, 4031650, 4031860, 4036007, 4041280, 4041358, 4041967, 4042126, 4043678, 4045588, 4054712, 4057974, 4062063, 4064166, 4065000, 4073925, 4077593, 4077594, 4077710, 4077715, 4077767, 4080131, 4080639, 4080899, 4081117, 4085815, 4085902, 4102098, 4102128, 4104538, 4106752, 4122689, 4122950, 4122978, 4124421, 4125995, 4126052, 4126104, 4126328, 4126488, 4127060, 4129474, 4129663, 4129721, 4129723, 4129881, 4129909, 4134477, 4134738, 4134781, 4135246, 4135354, 4135651, 4139008, 4139084, 4139523, 4139769, 4139770, 4139926, 4147541, 4150876, 4154112, 4159344, 4161316, 4161432, 4161534, 4161547, 4178499, 4269840, 4270110, 4270747, 4270761, 4270762, 4270763, 4270860, 4271179, 4271197, 4271580, 4271588, 4271602, 4271603, 4271647, 4271893, 4272687, 4272827, 4273314, 4273320, 4274190, 4274237, 4274417, 4275982, 4276011, 4276055, 4276069, 4276070, 4276185, 4276191, 4276232, 4277475, 4277489, 4277490, 4277496, 4277592, 4277605, 4278206, 4278534, 4278723, 4279157, 4279172, 4279890, 4279961, 4279984, 4280013, 4280053, 4280067, 4280071, 4280187, 4280280, 4280298, 4280303, 4280471, 4282651, 4283246, 4283305, 4283309, 4283325, 4283348, 4283362, 4283376, 4283377, 4283566, 4283580, 4283609, 4283667, 4283750, 4283769, 4283812, 4284150, 4284643, 4284653, 4284936, 4285044, 4285059, 4285268, 4285461, 4285500, 4285563, 4285947, 4286088, 4286104, 4286143, 4286161, 4286262, 4286335, 4286379, 4286625, 4286734, 4286740, 4286741, 4287030, 4287205, 4287843, 4287849, 4288408, 4288423, 4289249, 4289263, 4289511, 4289627, 4290090, 4290105, 4366839, 4366999, 4367013, 4367014, 4367028, 4367043, 4367071, 4367231, 4368195, 4368318, 4368406, 4368435, 4368492, 4369164, 4369913, 4369971, 4370203, 4370261, 4370377, 4370392, 4370595, 4372741, 4373417, 4373626, 4373771, 4375818, 4375988, 4376072, 4376090, 4376096, 4376172, 4376322, 4376328, 4376605, 4376728, 4376946, 4376966, 4377191, 4377497, 4377540, 4377546, 4377685, 4377743, 4377836, 4378004, 4378381, 4378426, 4380810, 4380932, 4380933, 4381031, 4381142, 4381155, 4381165, 4381194, 4381200, 4381316, 4382166, 4382191, 4382209, 4382215, 4382492, 4382499, 4382546, 4382600, 4382615, 4382716, 4382904, 4383032, 4383050, 4383079, 4383119, 4383339, 4383427, 4383441, 4384296, 4385137, 4386805, 4386819, 4387029, 4387661, 4388276, 4464569, 4464627, 4465032, 4465396, 4465406, 4465874, 4465947, 4465963, 4465985, 4466164, 4466338, 4466715, 4466759, 4467005, 4467585, 4467744, 4467802, 4467818, 4467919, 4467933, 4467939, 4467963, 4468382, 4468396, 4468397, 4468513, 4468821, 4468838, 4469302, 4469326, 4469528, 4472587, 4472602, 4473326, 4473374, 4473428, 4473442, 4473443, 4473444, 4473549, 4473617, 4473632, 4473642, 4473646, 4473652, 4473665, 4473675, 4473681, 4473733, 4473867, 4473901, 4475002, 4475049, 4475056, 4475096, 4475110, 4475124, 4475125, 4475241, 4475299, 4475392, 4475415, 4476328, 4477735, 4478098, 4478474, 4478488, 4478489, 4478591, 4478663, 4478721, 4478750, 4478872, 4478896, 4478913, 4479272, 4479423, 4479504, 4479765, 4479771, 4480102, 4480380, 4480414, 4480592, 4480606, 4481852, 4481853, 4483520, 4483534, 4483535, 4483652, 4485832, 4492133, 4500717, 4537272, 4537293, 4537375, 4537417, 4537468, 4537474, 4537519, 4537533, 4537539, 4537577, 4537585, 4537591, 4537597, 4537606, 4537612, 4537620, 4537678, 4537722, 4537742, 4537754, 4537757, 4537794, 4537834, 4537876, 4537881, 4537887, 4537916, 4537939, 4540601, 4540607, 4540657, 4540715, 4540722, 4540723, 4540724, 4540781, 4540941, 4541118, 4541186, 4541245, 4541264, 4541270, 4541303, 4544021, 4544029, 4544072, 4544086, 4544087, 4544316, 4544319, 4544336, 4544349, 4544406, 4544464, 4544465, 4544485, 4544508, 4544522, 4544523, 4549018, 4549046, 4549067, 4549075, 4549133, 4549191, 4549382, 4549408, 4549422, 4549452, 4549492, 4549510, 4549516, 4549528, 4549539, 4549550, 4549554, 4549596, 4549598, 4549650, 4549655, 4554092, 4554164, 4554179, 4554185, 4554195, 4554280, 4554339, 4554359, 4554397, 4554408, 4554428, 4554432, 4554468, 4554555, 4554574, 4554600, 4554620, 4554760, 4557462, 4557542, 4557848, 4557964, 4610729, 4610758, 4610764, 4612085, 4612672, 4613972, 4615578, 4615636, 4615674, 4615761, 4615864, 4616674, 4617225, 4617457, 4617515, 4617661, 4619748, 4619783, 4619875, 4619877, 4619879, 4619889, 4619951, 4619957, 4619986, 4620618, 4621412, 4621430, 4621432, 4621459, 4621668, 4621894, 4622271, 4622277, 4622503, 4622735, 4622741, 4624701, 4624713, 4624736, 4624742, 4624751, 4624790, 4624794, 4624816, 4624823, 4624828, 4624867, 4624877, 4624997, 4625003, 4625041, 4625055, 4625244, 4625247, 4625258, 4625264, 4625281, 4625414, 4625418, 4626052, 4626056, 4627231, 4627317, 4627345, 4627347, 4627535, 4627549, 4627592, 4627608, 4627723, 4627770, 4628169, 4629231, 4629452, 4629854, 4629869, 4629971, 4630709, 4630899, 4630957, 4683606, 4683627, 4683636, 4683751, 4683802, 4683803, 4683911, 4683938, 4683946, 4683998, 4684012, 4684099, 4684114, 4684118, 4684128, 4684142, 4684157, 4684273, 4686933, 4687042, 4687056, 4687057, 4687058, 4687174, 4687274, 4687300, 4687342, 4687420, 4687452, 4687492, 4690304, 4690334, 4690406, 4690420, 4690421, 4690479, 4690617, 4690813, 4690819, 4690828, 4690845, 4690856, 4690857, 4690863, 4691016, 4691037, 4695401, 4695467, 4695525, 4695713, 4695728, 4695729, 4695830, 4695839, 4695850, 4695862, 4695874, 4695891, 4695984, 4695989, 4695995, 4696062, 4700571, 4700630, 4700742, 4700908, 4700920, 4703876, 4757584, 4757620, 4758113, 4758716, 4759006, 4759360, 4759427, 4759447, 4760427, 4760597, 4761094, 4761100, 4761783, 4761970, 4762008, 4762018, 4762095, 4762115, 4762356, 4762370, 4764545, 4766314, 4766320, 4766937, 4766951, 4766952, 4766953, 4767754, 4767764, 4767770, 4767841, 4767847, 4767851, 4767967, 4767973, 4768060, 4771034, 4771036, 4771081, 4771360, 4771440, 4771446, 4771534, 4771540, 4771581, 4771752, 4771772, 4771983, 4771984, 4771991, 4771998, 4772107, 4772172, 4772410, 4772433, 4772439, 4773124, 4777870, 4777871, 4777885, 4777994, 4778500, 4856098, 4862811, 4862826, 4863667, 4868684, 4870816, 4977097, 4977121, 4977391, 4978845, 4978884, 4978928, 4978985, 4979000, 4979015, 4979025, 4979286, 4980581, 4980595, 4981392, 4981406, 4981407, 4983060, 4983298, 4983350, 4983466, 4983506, 4983524, 4983525, 4984220, 4985485, 4985496, 4985501, 4985594, 4985597, 4985612, 4985815, 4985821, 4985899, 4986077, 4986101, 4986337, 4986343, 4986348, 4986352, 4986362, 4986366, 4986438, 4986452, 4986453, 4986459, 4986553, 4986564, 4986667, 4986671, 4986691, 4986772, 4986918, 4987225, 4987271, 4987281, 4987297, 4987352, 4987421, 4987571, 4987729, 4988338, 4988961, 4988975, 4988976, 4989266, 4989301, 4990557, 4990567, 4990571, 4990611, 4990658, 4990774, 4990890, 4990909, 4991021, 4991082, 4991116, 4991499, 4991818, 4991824, 4991911, 4991934, 4992271, 4992325, 4992339, 4992340, 4992427, 4992629, 4993112, 4993129, 4993152, 4993158, 4993225, 4993312, 4993385, 4993413, 4993419, 4993443, 4993500, 4993558, 4993587, 4993776, 4993796, 4994015, 4994313, 4994811, 4994834, 4995037, 4995043, 4995182, 4995275, 4996422, 4996544, 4996754, 4997263, 4997269, 4997371, 4997385, 4997386, 4997671, 4997672, 5074120, 5074178, 5074280, 5074294, 5074352, 5075221, 5075769, 5075773, 5077455, 5077461, 5077600, 5077673, 5077688, 5081052, 5081080, 5083139, 5083154, 5083269, 5083377, 5083603, 5084002, 5084223, 5084247, 5084300, 5084727, 5084734, 5084850, 5084851, 5084950, 5084952, 5084956, 5084963, 5085117, 5085122, 5085134, 5085213, 5085223, 5085285, 5085291, 5085296, 5085314, 5085648, 5085792, 5088087, 5088113, 5088214, 5088403, 5088446, 5088452, 5088461, 5089447, 5089457, 5089476, 5089490, 5089780, 5090357, 5090969, 5093980, 5094100, 5147491, 5147519, 5147549, 5147683, 5150448, 5150855, 5159265, 5159315, 5167464, 5171735, 5172299, 5172307, 5172313, 5172314, 5172507, 5172778, 5173973, 5176113, 5176403, 5176592, 5176810, 5176969, 5180608, 5180695, 5180725, 5180927, 5180933, 5181049, 5181159, 5181558, 5181783, 5181884, 5181890, 5182337, 5182450, 5182464, 5182580, 5182673, 5183011, 5184373, 5184403, 5184413, 5184500, 5185741, 5185973, 5185979, 5186031, 5186055, 5186060, 5186147, 5186165, 5186194, 5186205, 5186839, 5186843, 5187046, 5187383, 5187452, 5188700, 5189119, 5189129, 5189133, 5189134, 5192726, 5244568, 5244597, 5244611, 5244617, 5244748, 5244755, 5244872, 5244886, 5244901, 5245003, 5245023, 5245046, 5245098, 5245103, 5245235, 5245255, 5247882, 5247883, 5247899, 5247903, 5247923, 5247961, 5247975, 5247989, 5247996, 5248004, 5248005, 5248120, 5248265, 5248266, 5248584, 5251245, 5251252, 5251281, 5251325, 5251384, 5251398, 5251528, 5251586, 5251600, 5251630, 5251766, 5251803, 5251810, 5256292, 5256299, 5256313, 5256317, 5256327, 5256333, 5256371, 5256460, 5256646, 5256698, 5256701, 5256733, 5256797, 5256820, 5256821, 5256879, 5256893, 5256936, 5257009, 5261445, 5261459, 5261460, 5261466, 5261518, 5261678, 5261692, 5261745, 5261881, 5261895, 5265132, 5293788, 5293794, 5293795, 5293813, 5293853, 5293935, 5293940, 5305410, 5305714, 5317778, 5317836, 5318010, 5318190, 5318242, 5318248, 5318527, 5318531, 5318551, 5318557, 5318567, 5318764, 5318938, 5320331, 5320539, 5320732, 5321374, 5321548, 5321578, 5321595, 5321612, 5322011, 5322041, 5322893, 5323143, 5324534, 5324784, 5325492, 5327029, 5327035, 5327052, 5327058, 5327087, 5327267, 5327363, 5327377, 5327808, 5327898, 5328200, 5328609, 5328671, 5328727, 5328739, 5328798, 5328838, 5328843, 5328914, 5328983, 5329017, 5329320, 5329345, 5329500, 5329552, 5329558, 5329587, 5329610, 5329616, 5329639, 5329755, 5329761, 5329769, 5329784, 5329790, 5329842, 5329848, 5329900, 5329906, 5329958, 5329964, 5329973, 5329993, 5330298, 5330530, 5331981, 5331988, 5332089, 5332104, 5332148, 5332220, 5332336, 5332342, 5332423, 5332496, 5332516, 5333033, 5333337, 5333717, 5333873, 5333879, 5334203, 5334558, 5334671, 5334801, 5334986, 5334989, 5335062, 5335068, 5336188, 5336280, 5336338, 5336483, 5336512, 5336570, 5337150, 5338101, 5338180, 5390851, 5390887, 5390902, 5390931, 5390945, 5390990, 5391004, 5391083, 5391085, 5391087, 5391188, 5391192, 5391203, 5391206, 5391217, 5391220, 5391235, 5391236, 5391279, 5391282, 5391299, 5391377, 5391380, 5391395, 5391453, 5391467, 5391473, 5391554, 5391569, 5394223, 5394237, 5394247, 5394266, 5394323, 5394330, 5394333, 5394338, 5394339, 5394382, 5394392, 5394449, 5394599, 5394730, 5394817, 5394860, 5394918, 5397630, 5397659, 5397673, 5397689, 5397718, 5397732, 5397760, 5397862, 5397898, 5397920, 5397940, 5397948, 5397963, 5397993, 5398007, 5398021, 5398022, 5398027, 5398094, 5398109, 5398114, 5398126, 5398137, 5398297, 5402627, 5402633, 5402725, 5402806, 5402859, 5402922, 5402962, 5402995, 5403025, 5403032, 5403053, 5403154, 5403161, 5403183, 5403199, 5403209, 5403213, 5403227, 5403247, 5403263, 5403265, 5403270, 5405474, 5405641, 5407707, 5407779, 5407787, 5407794, 5407809, 5407810, 5408012, 5408023, 5408040, 5408056, 5411086, 5411115, 5411318, 5411463, 5411478, 5464079, 5464384, 5464398, 5464402, 5464503, 5464518, 5464616, 5465997, 5466003, 5466664, 5467911, 5467912, 5468375, 5469071, 5470826, 5470840, 5471057, 5471058, 5471073, 5471098, 5471242, 5471261, 5473264, 5473275, 5473276, 5473323, 5473392, 5473393, 5473421, 5473427, 5473595, 5473601, 5473700, 5473711, 5473717, 5474142, 5474447, 5474455, 5474471, 5474552, 5475051, 5475118, 5475138, 5475248, 5475254, 5475341, 5477081, 5477708, 5478415, 5478482, 5478641, 5478647, 5478699, 5478821, 5478833, 5478902, 5479372, 5479464, 5479468, 5479472, 5479691, 5479710, 5479714, 5479720, 5480532, 5480537, 5480561, 5484310, 5484514, 5484616, 5484717, 5519894, 5561900, 5561958, 5562523, 5562538, 5565249, 5572108, 5572500, 5572732, 5572897, 5572920, 5574062, 5574602, 5574627, 5574637, 5574724, 5575871, 5575979, 5575994, 5576279, 5576284, 5576458, 5577676, 5579343, 5579358, 5683671, 5684019, 5684600, 5684672, 5684947, 5685316, 5685498, 5686042, 5686150, 5686165, 5686300, 5686572, 5687238, 5687441, 5687876, 5687970, 5688282, 5688746, 5689413, 5690304, 5690526, 5690544, 5690546, 5690573, 5690579, 5690631, 5690632, 5690747, 5690753, 5690794, 5691211, 5691327, 5691346, 5691930, 5691936, 5692034, 5692052, 5692162, 5692516, 5692770, 5692878, 5692892, 5692893, 5692904, 5692922, 5692994, 5693092, 5693096, 5693107, 5693125, 5693178, 5693183, 5693285, 5693328, 5693368, 5693372, 5693397, 5693611, 5693629, 5693719, 5693734, 5693972, 5694325, 5694456, 5694503, 5694506, 5694546, 5694552, 5694623, 5694633, 5694749, 5694755, 5694963, 5694967, 5695010, 5695605, 5695630, 5696146, 5696466, 5696566, 5696582, 5696721, 5697817, 5697910, 5697916, 5697924, 5697933, 5697939, 5698049, 5698113, 5698142, 5698171, 5698200, 5698229, 5698258, 5698264, 5698322, 5698334, 5698374, 5698873, 5698954, 5699099, 5699197, 5699201, 5699221, 5699552, 5699606, 5699620, 5699621, 5699690, 5700433, 5700491, 5700509, 5700650, 5700659, 5700665, 5700666, 5700723, 5700724, 5700854, 5700883, 5701298, 5701302, 5701303, 5701577, 5702129, 5702318, 5702376, 5702536, 5703811, 5703825, 5703832, 5704015, 5704546, 5704652, 5704660, 5704666, 5704667, 5781169, 5781175, 5781386, 5781401, 5781416, 5781517, 5781523, 5781560, 5781561, 5781575, 5781576, 5781605, 5781622, 5781633, 5781677, 5781697, 5782034, 5782039, 5782968, 5783054, 5783720, 5783765, 5783785, 5784533, 5784539, 5784765, 5785157, 5785824, 5785838, 5785844, 5787897, 5787973, 5788129, 5788303, 5788333, 5788634, 5789126, 5790434, 5790449, 5790561, 5790570, 5790648, 5790652, 5790681, 5790739, 5790740, 5790768, 5790928, 5791167, 5791290, 5791390, 5791401, 5791609, 5791615, 5792059, 5792062, 5792102, 5792175, 5792374, 5792398, 5792514, 5792566, 5793696, 5795408, 5795414, 5795466, 5795495, 5795698, 5795709, 5795727, 5795756, 5795780, 5795785, 5795878, 5795887, 5795937, 5796061, 5796655, 5796771, 5797177, 5797264, 5797949, 5798065, 5798841, 5799685, 5799699, 5799747, 5800410, 5800526, 5800540, 5800541, 5801114, 5801367, 5879016, 5880610, 5881262, 5881276, 5881277, 5882350, 5883887, 5883888, 5886190, 5886682, 5887149, 5887163, 5887164, 5887444, 5887888, 5887936, 5888204, 5888208, 5888214, 5888440, 5888463, 5888469, 5888755, 5889573, 5889658, 5889731, 5889745, 5889867, 5889954, 5890122, 5890644, 5891578, 5891684, 5891694, 5893036, 5893037, 5893050, 5893051, 5894304, 5894327, 5894664, 5894806, 5896400, 5896414, 5896415, 5899774, 6025073, 6025152, 6025450, 6025465, 6025471, 6025495, 6025848, 6026219, 6026944, 6027234, 6027480, 6027738, 6028829, 6030013, 6030129, 6030192, 6030221, 6030960, 6031750, 6031801, 6031990, 6032065, 6032208, 6032223, 6033382, 6033483, 6033498, 6033504, 6033614, 6033674, 6033956, 6034310, 6034324, 6034339, 6034345, 6034513, 6034524, 6034538, 6034548, 6034557, 6034571, 6034624, 6034629, 6034774, 6035064, 6035165, 6035179, 6035180, 6035186, 6035282, 6035398, 6035470, 6035900, 6035974, 6035992, 6036006, 6036020, 6036021, 6036027, 6036069, 6036079, 6036195, 6036201, 6036310, 6036793, 6036833, 6036839, 6036868, 6036891, 6037018, 6037036, 6037042, 6037071, 6037123, 6037181, 6037224, 6037239, 6037257, 6037297, 6037303, 6037341, 6037579, 6037688, 6037702, 6037703, 6038028, 6039258, 6039262, 6039269, 6039342, 6039501, 6039559, 6039603, 6039646, 6039652, 6039777, 6039792, 6039797, 6040211, 6040219, 6040221, 6040225, 6040226, 6040232, 6040643, 6040661, 6040998, 6041060, 6041062, 6041276, 6041508, 6041924, 6041937, 6041955, 6042154, 6042275, 6042285, 6042300, 6042343, 6042731, 6042734, 6042742, 6042744, 6042748, 6042749, 6043489, 6043567, 6043590, 6043606, 6043619, 6043764, 6043793, 6043953, 6043992, 6044416, 6044431, 6045156, 6045257, 6045461, 6172182, 6173278, 6174188, 6174394, 6175163, 6175656, 6175662, 6176467, 6176532, 6177773, 6178034, 6180557, 6180673, 6180847, 6180853, 6180872, 6180876, 6180882, 6181393, 6181398, 6181543, 6181614, 6181615, 6181803, 6181822, 6181833, 6181839, 6181978, 6182286, 6182529, 6182535, 6182790, 6185690, 6185922, 6185928, 6186078, 6186102, 6186126, 6186154, 6186653, 6186734, 6186740, 6186850, 6186972, 6187332, 6187499, 6193038, 6193062, 6240587, 6271860, 6271874, 6272585, 6272603, 6274068, 6275955, 6277388, 6278113, 6278217, 6278229, 6278329, 6279065, 6279331, 6279868, 6280853, 6281578, 6281592, 6281593, 6281662, 6281686, 6281883, 6283164, 6284401, 6285030, 6400918, 6400928, 6429717, 6443442, 6445573, 6445588, 6445877, 6446428, 6446638, 6446678, 6448110, 6448300, 6451736, 6455557, 6455780, 6456144, 6464352, 6467331, 6467338, 6467454, 6467716, 6471102, 6471109, 6473326, 6473340, 6473341, 6476049, 6476067, 6484470, 6484477, 6528730, 6537572, 6542288, 6546794, 6546972, 6575821, 6635074, 6635248, 6646848, 6662434, 6662443, 6663024, 6663030, 6665798, 6665799, 6665807, 6666388, 6668452, 6668877, 6683635, 6683645, 6683823, 6683958, 6684049, 6684315, 6684417, 6684882, 6690276, 6690286, 6690290, 6690566, 6690576, 6693687, 6693800, 6693804, 6696191, 6696199, 6696322, 6696415, 6696497, 6696579, 6698806, 6698822, 6708227, 6708300, 6708351, 6709116, 6709387, 6709655, 6709851, 6709940, 6710125, 6710126, 6710127, 6710507, 6710508, 6710601, 6710605, 6710786, 6710797, 6710870, 6710953, 6711664, 6711715, 6711722, 6712184, 6712306, 6712475, 6712544, 6712905, 6713215, 6713317, 6714993, 6716375, 6716389, 6716579, 6717121, 6717232, 6718898, 6718912, 6719030, 6720114, 6721060, 6722736, 6723119, 6723433, 6723952, 6723958, 6724068, 6724075, 6724318, 6724332, 6724985, 6725163, 6725172, 6725207, 6725524, 6726599, 6734992, 6734993, 6735003, 6743402, 6748232, 6769658, 6805493, 6805957, 6812235, 6812513, 6813931, 6817480, 6817557, 6817571, 6817577, 6830650, 6835812, 6839161, 6839176, 6839177, 6840728, 6845053, 6845513, 6846093, 6856089, 6857910, 6860200, 6909805, 6909820, 6915249, 6958482, 6962693, 6964003, 6968038, 7001010, 7001025, 7021558, 7098523, 7099336, 7099763, 7100060, 7100066, 7100712, 7100727, 7102293, 7102420, 7103134, 7103249, 7103308, 7103314, 7103365, 7103609, 7105135, 7106613, 7106614, 7106620, 7107336, 7107742, 7108195, 7108205, 7108209, 7108211, 7108296, 7108534, 7108581, 7109137, 7109398, 7109404, 7109578, 7110698, 7110804, 7110818, 7110819, 7112675, 7112704, 7112820, 7112918, 7112958, 7113348, 7113647, 7113661, 7114473, 7115198, 7115286, 7115343, 7115749, 7115858, 7116585, 7116691, 7117649, 7119844, 7196021, 7196195, 7196594, 7198399, 7199327, 7199333, 7199443, 7199501, 7199719, 7199965, 7199994, 7201516, 7202111, 7204878, 7205210, 7205214, 7205220, 7205751, 7206090, 7206960, 7207795, 7210158, 7210231, 7210237, 7210440, 7211290, 7211333, 7211362, 7211739, 7212024, 7212180, 7212827, 7214247, 7214255, 7214364, 7269112, 7293606, 7294052, 7294070, 7294142, 7294156, 7294157, 7297550, 7297556, 7298426, 7298653, 7299072, 7302770, 7303002, 7303646, 7303733, 7304303, 7304423, 7304496, 7304500, 7304510, 7307584, 7307653, 7307976, 7308686, 7310962, 7310976, 7310977, 7310983, 7415504, 7440476, 7440491, 7441502, 7441506, 7441512, 7441622, 7442166, 7443594, 7445798, 7446639, 7446640, 7446785, 7448045, 7448059, 7448060, 7448066, 7448343, 7448886, 7448901, 7449104, 7449110, 7449336, 7449342, 7450027, 7450032, 7450583, 7450641, 7451024, 7451598, 7453825, 7454405, 7455513, 7456070, 7456088, 7456347, 7456528, 7456644, 7456659, 7456847, 7456877, 7458035, 7458137, 7458355, 7458361, 7459819, 7586709, 7586825, 7587956, 7588391, 7588551, 7588571, 7588797, 7590907, 7591488, 7592640, 7592660, 7594017, 7594379, 7594394, 7594858, 7595232, 7595959, 7595985, 7595995, 7596395, 7596401, 7596855, 7597091, 7597097, 7597334, 7597348, 7598599, 7598884, 7600158, 7600166, 7600274, 7600281, 7600570, 7601557, 7601963, 7602404, 7603645, 7603747, 7805699, 7806193, 7806209, 7806599, 7807236, 7807341, 7807882, 7808008, 7808124, 7809805, 7810821, 7810995, 7811249, 7812089, 7812184, 7812213, 7812242, 7812369, 7812387, 7812416, 7812445, 7812462, 7812474, 7813779, 7814011, 7814614, 7814736, 7814839, 7814852, 7815037, 7815461, 7815577, 7815685, 7815693, 7815795, 7816288, 7816302, 7816395, 7816407, 7816418, 7816458, 7816476, 7816553, 7816853, 7817029, 7817520, 7817984, 7818187, 7818382, 7818394, 7818401, 7818564, 7818565, 7819666, 7819667, 7819956, 7819996, 7820043, 7820068, 7820072, 7820149, 7820189, 7820194, 7820217, 7820397, 7820692, 7820841, 7821341, 7821348, 7821580, 7821889, 7822352, 7822672, 7822684, 7822688, 7823023, 7823030, 7823871, 7823873, 7823972, 7825545, 7825858, 7825959, 7826626, 7826796, 7830251, 7903749, 7903765, 7904155, 7904897, 7905608, 7905628, 7906336, 7906434, 7906579, 7906608, 7906619, 7906662, 7906666, 7906724, 7906840, 7906884, 7907361, 7909374, 7910146, 7912289, 7912402, 7912495, 7912501, 7912611, 7912617, 7912727, 7912733, 7913042, 7913046, 7913452, 7913453, 7913458, 7913845, 7913905, 7913941, 7914264, 7914395, 7914409, 7915772, 7917251, 7917331, 7917367, 7917518, 7917552, 7917585, 7917599, 7917628, 7917721, 7917817, 7918062, 7918614, 7918904, 7918951, 7919020, 7919303, 7920695, 7920811, 7922384, 7923210, 7923224, 7976169, 7976237, 7976372, 7976469, 7976516, 7976643, 7976770, 7976807, 7979525, 7979996, 7980007, 7988109, 7988359, 7988440, 7992999, 8000482, 8000531, 8000846, 8001337, 8001341, 8001428, 8001438, 8001623, 8002453, 8002459, 8003087, 8003097, 8003113, 8003164, 8003990, 8004338, 8004440, 8004454, 8005731, 8009007, 8009287, 8009854, 8009958, 8010051, 8010109, 8010138, 8010167, 8010283, 8010289, 8010598, 8011414, 8011483, 8011501, 8011507, 8011530, 8011588, 8011641, 8011704, 8011710, 8011820, 8012734, 8012769, 8013212, 8013513, 8013531, 8014813, 8015068, 8015155, 8015261, 8015614, 8016147, 8016170, 8016561, 8016575, 8016576, 8016649, 8016663, 8017017, 8017033, 8018142, 8019215, 8074013, 8074083, 8077564, 8080426, 8080480, 8080666, 8085787, 8085815, 8090628, 8091026, 8122476, 8122600, 8122861, 8122912, 8122919, 8123005, 8123021, 8123050, 8125775, 8125891, 8125899, 8125906, 8126102, 8126110, 8129285, 8129665, 8129705, 8129749, 8134229, 8134250, 8134512, 8134635, 8134719, 8134751, 8134781, 8134824, 8134833, 8134838, 8139275, 8139732, 8139738, 8139739, 8139797, 8147116, 8147192, 8147250, 8147651, 8148062, 8150498, 8151165, 8151553, 8151854, 8152702, 8152813, 8153021, 8153906, 8154616, 8155219, 8155225, 8155340, 8155700, 8155799, 8156066, 8156472, 8156938, 8157779, 8157831, 8158038, 8158879, 8158885, 8159072, 8159538, 8159633, 8159677, 8159847, 8161102, 8161112, 8161113, 8161228, 8161442, 8161518, 8161687, 8161692, 8162069, 8162841, 8163200, 8163204, 8164110, 8219975, 8220011, 8220128, 8220214, 8220215, 8220347, 8220359, 8220366, 8220563, 8220678, 8223459, 8223711, 8223970, 8227022, 8232115, 8232119, 8232157, 8236821, 8237171, 8244864, 8294208, 8296078, 8296252, 8296399, 8297586, 8298304, 8299877, 8302042, 8302139, 8302392, 8302395, 8302400, 8302535, 8303473, 8303937, 8307678, 8307925, 8307979, 8308026, 8308805, 8309128, 8309175, 8309360, 8309708, 8314754, 8318060, 8321214, 8345592, 8390755, 8390995, 8391070, 8391198, 8391256, 8391952, 8393751, 8400072, 8400174, 8400183, 8400193, 8400348, 8401711, 8401725, 8403436, 8405220, 8406800, 8406873, 8513133, 8513560, 8513600, 8513705, 8513723, 8513896, 8513966, 8514433, 8514447, 8514448, 8514449, 8514454, 8514622, 8515266, 8515274, 8515284, 8515288, 8515289, 8515478, 8516507, 8516619, 8517691, 8517811, 8518530, 8518536, 8519425, 8519436, 8519465, 8519541, 8519650, 8519668, 8519856, 8519871, 8519877, 8519918, 8519935, 8521161, 8521175, 8521176, 8521263, 8521287, 8521564, 8521994, 8522220, 8522742, 8522771, 8522777, 8522843, 8522857, 8522858, 8522956, 8523096, 8523177, 8523573, 8523583, 8523627, 8523630, 8523656, 8523670, 8523699, 8523873, 8523879, 8524134, 8525374, 8525492, 8525497, 8525512, 8525773, 8526994, 8527034, 8527040, 8527049, 8527063, 8527107, 8527117, 8527179, 8527237, 8527243, 8527324, 8527325, 8527521, 8527538, 8527889, 8527903, 8527904, 8528300, 8528306, 8528312, 8528316, 8528339, 8528618, 8528676, 8528730, 8528740, 8528744, 8528750, 8528818, 8528822, 8528832, 8528838, 8528905, 8529166, 8529209, 8529465, 8529517, 8529557, 8529615, 8529633, 8529832, 8529945, 8530304, 8530311, 8530412, 8530426, 8530427, 8530786, 8530926, 8531253, 8531267, 8531379, 8531442, 8531529, 8531535, 8532094, 8532108, 8532109, 8532318, 8532935, 8532949, 8533061, 8533411, 8533674, 8533776, 8533791, 8534081, 8534390, 8534396, 8610253, 8610293, 8610299, 8610351, 8610357, 8610496, 8610502, 8610554, 8610583, 8610584, 8610589, 8610641, 8610642, 8610685, 8610694, 8610699, 8610705, 8610714, 8610717, 8610729, 8610757, 8610801, 8610821, 8610917, 8611158, 8611952, 8612845, 8612889, 8612909, 8613303, 8613569, 8613617, 8613860, 8614005, 8614063, 8614069, 8614078, 8614121, 8614281, 8614948, 8614962, 8614968, 8615245, 8615367, 8615368, 8615412, 8615422, 8615432, 8615485, 8616586, 8616655, 8617427, 8617445, 8620293, 8620399, 8620407, 8620414, 8620628, 8620632, 8620699, 8620877, 8621226, 8621255, 8621313, 8621371, 8621545, 8621647, 8621680, 8621686, 8622343, 8622936, 8623038, 8623296, 8623354, 8624604, 8624619, 8624938, 8625369, 8625460, 8625895, 8626232, 8626374, 8626762, 8627073, 8627080, 8627113, 8627189, 8627867, 8627969, 8627976, 8627982, 8627983, 8628094, 8628099, 8628940, 8629665, 8629781, 8631347, 8631938, 8631962, 8708121, 8708125, 8708127, 8708240, 8708255, 8708256, 8708712, 8708718, 8709078, 8709086, 8709637, 8709648, 8709677, 8709734, 8710284, 8710691, 8711271, 8711286, 8711503, 8711619, 8711706, 8711721, 8712082, 8712083, 8712518, 8712803, 8712923, 8712968, 8713012, 8713171, 8716273, 8716283, 8716287, 8716288, 8716399, 8716997, 8717332, 8717419, 8717564, 8717849, 8717854, 8717859, 8717865, 8717873, 8717879, 8717883, 8717889, 8717955, 8717965, 8717970, 8717976, 8718087, 8718184, 8718231, 8718260, 8718271, 8718289, 8718679, 8718742, 8718782, 8718788, 8718796, 8718806, 8718810, 8718811, 8718817, 8718851, 8718865, 8718869, 8718875, 8718922, 8718985, 8718991, 8719072, 8719078, 8719101, 8719246, 8719252, 8719269, 8719280, 8719913, 8720377, 8720493, 8720594, 8720604, 8720783, 8720818, 8722059, 8722349, 8722466, 8723190, 8723205, 8723335, 8723451, 8723731, 8723741, 8723842, 8723856, 8723857, 8723930, 8723940, 8723944, 8724220, 8725524, 8725534, 8725538, 8725539, 8725640, 8725828, 8725902, 8781259, 8781302, 8854143, 8854183, 8854241, 8854444, 8854450, 8854473, 8854619, 8854636, 8854691, 8854936, 8854972, 8855038, 8855053, 8855343, 8855517, 8855771, 8855967, 8855981, 8855996, 8856010, 8856068, 8856184, 8856352, 8856358, 8856730, 8856735, 8856779, 8857982, 8858852, 8858858, 8859137, 8859258, 8859322, 8859356, 8859358, 8859360, 8859369, 8859538, 8859543, 8859548, 8859978, 8860911, 8861201, 8861245, 8861317, 8862622, 8863666, 8863672, 8863741, 8864188, 8864289, 8864304, 8865144, 8865185, 8865189, 8865203, 8865905, 8865971, 8865992, 8865997, 8866015, 8866088, 8866160, 8866166, 8866218, 8866247, 8866253, 8866305, 8866311, 8866348, 8866378, 8866421, 8866812, 8866826, 8866827, 8867152, 8868386, 8868388, 8868480, 8868486, 8868502, 8868509, 8868609, 8868625, 8868892, 8869335, 8869343, 8869345, 8869349, 8869350, 8869762, 8870064, 8870075, 8870191, 8870291, 8870481, 8870579, 8870608, 8870612, 8870622, 8870655, 8871017, 8871079, 8871134, 8871148, 8871246, 8871278, 8871293, 8871337, 8871351, 8871395, 8871409, 8871415, 8871424, 8871444, 8871467, 8871758, 8871858, 8871872, 8871873, 8871879, 8871975, 8872593, 8872699, 8872713, 8872714, 8872923, 8872960, 8872961, 8872975, 8873033, 8873097, 8873106, 8873149, 8873540, 8873555, 8874273, 8875232, 9000477, 9001010, 9001372, 9001387, 9001746, 9002228, 9002329, 9002402, 9002408, 9002591, 9003069, 9003169, 9003359, 9004762, 9004780, 9005577, 9005591, 9005592, 9010000, 9010261, 9010522, 9010541, 9010633, 9010856, 9010876, 9010957, 9010963, 9011362, 9011363, 9011410, 9011479, 9011480, 9011523, 9011527, 9011537, 9011653, 9011914, 9013272, 9013625, 9014774, 9014828, 9014842, 9014843, 9014848, 9014891, 9014907, 9014944, 9015017, 9015226, 9015322, 9015683, 9015777, 9015858, 9016119, 9016125, 9016148, 9016507, 9016510, 9016524, 9016525, 9018706, 9019874, 9019888, 9019889, 9052804, 9098334, 9098464, 9098480, 9098494, 9099766, 9099770, 9100143, 9105079, 9108194, 9108502, 9108966, 9109035, 9112399, 9112499, 9113225, 9113240, 9113351, 9113356, 9113523, 9113628, 9114066, 9114081, 9114154, 9114181, 9114498, 9220032, 9220410, 9220424, 9220888, 9221613, 9221617, 9221714, 9221728, 9221729, 9221802, 9221806, 9221812, 9221816, 9221903, 9221918, 9222014, 9222102, 9222448, 9222614, 9222671, 9222779, 9222933, 9223781, 9224350, 9224352, 9225093, 9225151, 9225193, 9225307, 9225384, 9225557, 9226210, 9226380, 9226438, 9226746, 9226945, 9226949, 9226964, 9226971, 9226978, 9227007, 9227036, 9227138, 9227152, 9227167, 9227182, 9227370, 9227905, 9228340, 9228456, 9228457, 9228573, 9228747, 9229176, 9229181, 9229182, 9229187, 9229298, 9229398, 9229406, 9229414, 9229501, 9229530, 9229536, 9229733, 9229739, 9229762, 9229773, 9229802, 9230023, 9230034, 9230048, 9230125, 9230145, 9230241, 9230353, 9230377, 9230864, 9230865, 9230941, 9230963, 9230965, 9230969, 9230980, 9230981, 9231020, 9231024, 9231034, 9231038, 9231044, 9231078, 9231080, 9231096, 9231160, 9231189, 9231223, 9231269, 9231270, 9231372, 9231397, 9231411, 9231421, 9231437, 9231444, 9232548, 9232851, 9233957, 9234214, 9234217, 9234228, 9234275, 9234286, 9234321, 9234344, 9234388, 9234402, 9234442, 9234445, 9234500, 9234518, 9234533, 9234547, 9234553, 9234576, 9234629, 9234633, 9234703, 9234711, 9234727, 9234736, 9234739, 9234779, 9234843, 9235069, 9235170, 9235184, 9235185, 9235278, 9235359, 9235470, 9235573, 9235577, 9235597, 9235620, 9235626, 9235649, 9235900, 9235957, 9236011, 9236025, 9236026, 9236032, 9236113, 9236137, 9236225, 9236447, 9236461, 9236617, 9236631, 9236786, 9236838, 9237070, 9237113, 9237234, 9237279, 9237302, 9237346, 9237482, 9237703, 9237810, 9237819, 9237824, 9238534, 9238723, 9238729, 9238810, 9238926, 9239375, 9239390, 9239500, 9239506, 9240110, 9240115, 9240130, 9240216, 9240520, 9240521, 9240638, 9241057, 9241071, 9241072, 9241078, 9241537, 9251523, 9317574, 9317806, 9317812, 9317850, 9317864, 9317865, 9317870, 9317966, 9317995, 9318027, 9318038, 9318044, 9318734, 9319209, 9319372, 9319459, 9320003, 9320224, 9320226, 9320232, 9320315, 9320938, 9321344, 9321402, 9321837, 9324592, 9324593, 9324598, 9324755, 9326169, 9326738, 9326749, 9326825, 9326840, 9326954, 9326962, 9327057, 9327139, 9327289, 9327295, 9327695, 9327985, 9328405, 9328507, 9328521, 9328536, 9328580, 9328594, 9328924, 9328928, 9328931, 9328953, 9328967, 9329000, 9329841, 9329899, 9331831, 9331842, 9331848, 9331857, 9331885, 9331893, 9331899, 9331900, 9331944, 9332009, 9332016, 9332017, 9332074, 9332089, 9332103, 9332283, 9332292, 9332618, 9332625, 9332734, 9332741, 9332834, 9332841, 9332988, 9333026, 9333153, 9333172, 9333459, 9333567, 9333581, 9333582, 9333665, 9333669, 9333682, 9333685, 9333698, 9333865, 9333945, 9333955, 9334815, 9335249, 9335264, 9336090, 9336308, 9338049, 9338628, 9339223, 9339233, 9402920, 9415451, 9415453, 9415559, 9415616, 9416725, 9416914, 9416929, 9417015, 9417021, 9417131, 9417200, 9417214, 9417659, 9417726, 9417746, 9417798, 9418089, 9418784, 9419002, 9419393, 9419399, 9419805, 9420263, 9420293, 9420930, 9421162, 9423554, 9423569, 9423928, 9424044, 9424341, 9424381, 9424409, 9424410, 9424439, 9424508, 9424580, 9424584, 9424590, 9424599, 9424613, 9424699, 9424700, 9424711, 9424845, 9424851, 9424852, 9425236, 9425247, 9425250, 9425251, 9425351, 9425663, 9425962, 9425975, 9425976, 9426020, 9426040, 9426063, 9426077, 9426091, 9426092, 9426136, 9426150, 9426156, 9426202, 9426208, 9426252, 9426266, 9426359, 9426381, 9426382, 9426527, 9426533, 9426556, 9429441, 9429456, 9429461, 9429557, 9429567, 9429630, 9429636, 9429665, 9429746, 9429880, 9429949, 9430047, 9430228, 9430264, 9430296, 9430297, 9430351, 9430587, 9430685, 9430709, 9430732, 9430738, 9431069, 9431137, 9431138, 9431254, 9431501, 9431573, 9434487, 9434497, 9434501, 9434502, 9434923, 9434963, 9436179, 9436799, 9540686, 9561754, 9561928, 9562253, 9562334, 9562340, 9562436, 9562523, 9563244, 9563252, 9563262, 9563268, 9563349, 9563893, 9564009, 9564010, 9564015, 9564016, 9564060, 9564122, 9564480, 9565234, 9565988, 9566603, 9566996, 9567373, 9567670, 9567757, 9567826, 9567843, 9568410, 9568424, 9568580, 9568584, 9568628, 9568656, 9569352, 9569903, 9570019, 9570193, 9570621, 9570627, 9570628, 9570715, 9570721, 9570729, 9570744, 9570929, 9570947, 9570953, 9571175, 9571179, 9571324, 9571585, 9571799, 9571823, 9572049, 9572295, 9572303, 9572310, 9572383, 9572393, 9572425, 9572432, 9572480, 9572484, 9572526, 9572528, 9572600, 9572716, 9572847, 9572850, 9572867, 9573180, 9573198, 9573238, 9573244, 9573252, 9573441, 9573452, 9573460, 9573470, 9573476, 9573516, 9573528, 9573534, 9573586, 9573644, 9573650, 9573659, 9573702, 9573746, 9574093, 9574107, 9574108, 9574114, 9574433, 9574520, 9574572, 9575660, 9575667, 9575674, 9575675, 9575680, 9575783, 9575790, 9575888, 9576037, 9576051, 9576075, 9576098, 9576149, 9576163, 9576182, 9576225, 9576616, 9576631, 9576637, 9576805, 9577033, 9577043, 9577052, 9577066, 9577457, 9577465, 9577467, 9577472, 9577473, 9577478, 9577559, 9577565, 9577575, 9577588, 9577761, 9577893, 9577903, 9577907, 9577913, 9577936, 9578063, 9578244, 9578313, 9578501, 9578516, 9578517, 9578559, 9578575, 9578632, 9578685, 9578705, 9578720, 9578725, 9578737, 9579139, 9579153, 9579154, 9579256, 9579879, 9579980, 9579994, 9580354, 9580720, 9580938, 9581561, 9581866, 9581880, 9582503, 9582518, 9582808, 9600180, 9709592, 9709609, 9709610, 9709620, 9709683, 9709689, 9709798, 9709882, 9709973, 9710327, 9710394, 9710465, 9710709, 9712061, 9712457, 9712913, 9712931, 9712937, 9713923, 9714526, 9714758, 9714932, 9714962, 9715504, 9717009, 9717021, 9717063, 9717078, 9717084, 9717118, 9717180, 9717263, 9717281, 9717470, 9717473, 9718151, 9718157, 9718238, 9718331, 9718383, 9718691, 9718800, 9718804, 9718814, 9718818, 9718876, 9719195, 9719197, 9719862, 9722001, 9722008, 9722109, 9722124, 9722129, 9722225, 9722298, 9722304, 9722327, 9722333, 9722483, 9722487, 9722491, 9722501, 9722507, 9722549, 9722599, 9722848, 9722889, 9722950, 9722964, 9722965, 9722966, 9723034, 9723058, 9723065, 9723082, 9723139, 9723145, 9723255, 9723357, 9723377, 9723389, 9723400, 9723406, 9723737, 9723791, 9723805, 9723806, 9723875, 9723922, 9724091, 9724241, 9727155, 9727170, 9728728, 9729467, 9749225, 9805579, 9814517, 9816189, 9816200, 9817258, 9817371, 9817462, 9817476, 9817482, 9820046, 9820622, 9821439, 9821449, 9821455, 9825016, 9833136, 9927560, 9927589, 9928169, 9928285, 9928343, 9928354, 9928442, 9929083, 9929093, 9929373, 9929735, 9929966, 9931171, 9931175, 9931562, 9932258, 9932438, 9932663, 9933092, 9933925, 9934085, 9934230, 9934259, 9934415, 9934435, 9934535, 9935723, 9935737, 9935749, 9936463, 9936564, 9936764, 9937405, 9937419, 9937420, 9937634, 9937884, 9938145, 9938189, 9938209, 9938234, 9938504, 9938528, 9938551, 9938707, 9939827, 9939928, 9939943, 9940229, 9940244, 9940248, 9940268, 9941509, 9941563, 9941567, 9941596, 9941625, 9941725, 9941741, 9941799, 9941805, 9941988, 9942343, 9942543, 9942907, 9943184, 9943238, 9943307, 9943423, 9943748, 9944079, 9944410, 9944866, 9944872, 9944982, 9944989, 9944993, 9945488, 9945697, 9948353, 9948468, 9948469, 10017442, 10024899, 10025087, 10025203, 10025261, 10025308, 10025319, 10025717, 10025718, 10026740, 10027384, 10028219, 10028225, 10028436, 10028437, 10028463, 10028480, 10028567, 10028640, 10028843, 10030303, 10030394, 10031641, 10031659, 10032019, 10032091, 10034251, 10034344, 10034860, 10035237, 10035296, 10035817, 10036919, 10037498, 10037616, 10039058, 10039152, 10039158, 10039337, 10039355, 10039464, 10039616, 10039623, 10039660, 10039680, 10040457, 10040863, 10041150, 10041298, 10041304, 10041878, 10041922, 10041936, 10042538, 10042544, 10042545, 10043009, 10043299, 10043371, 10043379, 10043385, 10043488, 10043595, 10043798, 10043821, 10044662, 10044691, 10073774, 10073905, 10074087, 10085650, 10092150, 10123991, 10124121, 10124195, 10124210, 10124296, 10124405, 10124840, 10124948, 10124963, 10125252, 10125427, 10125732, 10125833, 10126283, 10126645, 10126674, 10126680, 10127086, 10127485, 10127573, 10127574, 10128436, 10130717, 10130723, 10130736, 10131894, 10132435, 10132445, 10132851, 10133372, 10133373, 10133500, 10133640, 10133658, 10133797, 10134939, 10135380, 10135763, 10136940, 10136946, 10137568, 10137970, 10138042, 10138418, 10138419, 10138520, 10138704, 10138782, 10138792, 10138802, 10138860, 10139978, 10141768, 10141782, 10141783, 10141885, 10143343, 10183388, 10268861, 10269534, 10269804, 10269824, 10270630, 10271174, 10271274, 10271297, 10271761, 10272341, 10273878, 10274955, 10275705, 10275711, 10275764, 10275879, 10275894, 10275914, 10278025, 10278228, 10278315, 10278735, 10278750, 10278861, 10278866, 10278966, 10279146, 10279156, 10279591, 10279635, 10279707, 10279708, 10279765, 10279823, 10279974, 10280280, 10280461, 10280665, 10280722, 10280728, 10280809, 10280867, 10280940, 10281266, 10283169, 10283187, 10283245, 10283285, 10283303, 10283495, 10283897, 10283911, 10283912, 10283918, 10284630, 10284684, 10284738, 10284752, 10284753, 10285211, 10285782, 10285783, 10285797, 10285855, 10285861, 10285913, 10286305, 10286319, 10286428, 10286893, 10287166, 10287261, 10287479, 10287711, 10287717, 10287994, 10288117, 10288844, 10288943, 10288958, 10415868, 10416047, 10416859, 10417608, 10417630, 10417631, 10417675, 10417733, 10418229, 10419342, 10420024, 10423503, 10423518, 10424544, 10424562, 10425113, 10425195, 10425438, 10426308, 10429376, 10429382, 10429608, 10429782, 10429797, 10431489, 10431499, 10431528, 10512680, 10512849, 10519641, 10522200, 10523064, 10528190, 10643004, 10643014, 10643018, 10643477, 10648891, 10649747, 10649863, 10650182, 10650583, 10650587, 10650675, 10652270, 10659247, 10662235, 10662555, 10662754, 10662942, 10665610, 10665711, 10665843, 10666161, 10666568, 10670772, 10670961, 10670965, 10670975, 10684947, 10685532, 10686630, 10689273, 10689274, 10689637, 10689657, 10691796, 10697666, 10699235, 10699243, 10699245, 10699250, 10699367, 10699939, 10700400, 10707863, 10707979, 10710298, 10710508, 10711425, 10711598, 10714904, 10714911, 10714962, 10719927, 10720013, 10728149, 10728163, 10757134, 10760636, 10760744, 10760758, 10760861, 10765677, 10772528, 10772532, 10781175, 10784292, 10784307, 10786100, 10786708, 10788150, 10790179, 10790193, 10790194, 10791135, 10795240, 10811944, 10812309, 10815524, 10817353, 10832342, 10838957, 10838971, 10840828, 10841089, 10861056, 10878808, 10879079, 10881950, 10892680, 10894362, 10905406, 10905410, 10905513, 10906352, 10906363, 10906514, 10906542, 10906710, 10909906, 10910095, 10912023, 10912026, 10912138, 10912241, 10914726, 10915373, 10915503, 10927665, 10928117, 10928481, 10928582, 10930681, 10930902, 10931018, 10934440, 10936401, 10939544, 10941447, 10953347, 10953348, 10953349, 10953812, 10955239, 10955407, 10955413, 10958679, 10958852, 10960540, 10960807, 10962584, 10966805, 10967745, 10977013, 10981927, 10986455, 10987423, 10988655, 10990454, 10991193, 10992135, 10997065, 10997080, 11000531, 11000647, 11004069, 11004113, 11004167, 11007419, 11007421, 11007630, 11013349, 11015693, 11016408, 11016422, 11016523, 11049153, 11049170, 11049425, 11049471, 11049484, 11049643, 11050048, 11050180, 11050788, 11051015, 11051744, 11052035, 11052455, 11053427, 11054147, 11054366, 11054368, 11054718, 11055853, 11055943, 11056483, 11058473, 11058758, 11058760, 11059313, 11060271, 11061948, 11063635, 11065284, 11066760, 11070537, 11075176, 11079366, 11080338, 11083803, 11083986, 11088847, 11089688, 11098176, 11098383, 11103886, 11113862, 11113978, 11114269, 11123347, 11124159, 11126014, 11127523, 11129244, 11131814, 11132365, 11133250, 11133299, 11134888, 11134989, 11135077, 11135091, 11136788, 11137520, 11137962, 11138287, 11138335, 11138353, 11138367, 11138422, 11138731, 11138741, 11138785, 11143407, 11143704, 11146981, 11147161, 11147199, 11150345, 11150403, 11150519, 11150520, 11153753, 11153913, 11158653, 11158745, 11158751, 11163787, 11172922, 11177895, 11178144, 11180403, 11180417, 11180419, 11182085, 11182099, 11185464, 11185566, 11186300, 11196691, 11199297, 11199486, 11202241, 11202255, 11202284, 11202502, 11202516, 11204920, 11211534, 11223686, 11247862, 11256355, 11265461, 11342093, 11342151, 11343456, 11343557, 11343571, 11343572, 11343578, 11343641, 11343746, 11343862, 11343989, 11344007, 11344390, 11344529, 11344602, 11344627, 11344776, 11344780, 11345133, 11345221, 11345539, 11346815, 11346921, 11346935, 11346936, 11347125, 11347211, 11347303, 11348792, 11348821, 11348879, 11348880, 11348981, 11348995, 11349006, 11349010, 11350456, 11351025, 11351031, 11351123, 11351126, 11351140, 11351141, 11351142, 11351257, 11351305, 11351326, 11351344, 11351350, 11351373, 11351379, 11351450, 11351460, 11351529, 11351553, 11352283, 11352712, 11352751, 11352776, 11352794, 11352800, 11352808, 11352816, 11352823, 11352863, 11352881, 11352923, 11352933, 11352993, 11353070, 11353074, 11353235, 11353254, 11353258, 11353264, 11353857, 11353863, 11354382, 11354389, 11354393, 11354504, 11354505, 11354814, 11354830, 11356057, 11356071, 11356118, 11356148, 11356158, 11356164, 11356172, 11356182, 11356183, 11356186, 11356187, 11356189, 11356190, 11356231, 11356241, 11356303, 11356343, 11356351, 11356361, 11356367, 11356376, 11356396, 11356401, 11356470, 11356506, 11356546, 11356564, 11356570, 11356579, 11356612, 11356622, 11356628, 11356666, 11356676, 11357024, 11357028, 11357097, 11357109, 11357184, 11357353, 11357420, 11357445, 11357449, 11357463, 11357469, 11357800, 11357854, 11357864, 11357868, 11357869, 11357956, 11357980, 11357981, 11358025, 11358078, 11358281, 11358290, 11358310, 11358333, 11358594, 11358913, 11359102, 11359430, 11359536, 11359546, 11359550, 11359551, 11359652, 11359662, 11359667, 11360276, 11360280, 11360377, 11360391, 11360566, 11360572, 11360595, 11360653, 11361233, 11362059, 11362060, 11362073, 11362363, 11362465, 11439374, 11439377, 11439490, 11439504, 11439620, 11439626, 11439649, 11439707, 11439809, 11439816, 11439823, 11439824, 11439838, 11439881, 11439887, 11439945, 11440041, 11440171, 11441007, 11441215, 11441288, 11441302, 11441308, 11441846, 11441969, 11442085, 11442342, 11442738, 11442984, 11443013, 11443071, 11443106, 11443115, 11443129, 11443187, 11443193, 11443289, 11444556, 11444579, 11446435, 11446566, 11446769, 11447735, 11447856, 11447967, 11448132, 11448581, 11448583, 11448697, 11448726, 11448900, 11448911, 11448982, 11449114, 11449132, 11450256, 11450307, 11450350, 11450352, 11450364, 11450379, 11450433, 11450481, 11450495, 11450553, 11450622, 11450626, 11450630, 11450646, 11450814, 11451481, 11451487, 11452246, 11452351, 11452386, 11452457, 11453617, 11453671, 11453674, 11453685, 11453716, 11453720, 11453725, 11453743, 11453852, 11453859, 11453917, 11453923, 11453975, 11454004, 11454021, 11454062, 11454126, 11454135, 11454150, 11454178, 11454222, 11454232, 11454236, 11454242, 11454764, 11454874, 11455015, 11455019, 11455025, 11455302, 11455308, 11455424, 11455542, 11455788, 11455792, 11455850, 11455856, 11455866, 11457934, 11458065, 11460022, 11512657, 11512862, 11512976, 11512984, 11513005, 11515976, 11516224, 11516262, 11516369, 11519218, 11519736, 11519957, 11522159, 11524311, 11524634, 11524644, 11524648, 11524654, 11524818, 11524822, 11525316, 11529868, 11532982, 11532986, 11537253, 11537259, 11537263, 11537720, 11538561, 11538568, 11538632, 11538858, 11538864, 11538975, 11539043, 11539141, 11540294, 11540612, 11540743, 11540744, 11540783, 11540801, 11540807, 11540971, 11542112, 11542136, 11542286, 11543979, 11545296, 11545298, 11545397, 11545411, 11545412, 11545418, 11545528, 11545869, 11546123, 11546124, 11546133, 11546136, 11546139, 11546141, 11546184, 11546235, 11546238, 11546243, 11546252, 11546253, 11546369, 11546456, 11546544, 11546547, 11546711, 11546717, 11546978, 11547413, 11547819, 11547866, 11547920, 11547935, 11547941, 11547989, 11547993, 11548105, 11548109, 11548115, 11548178, 11548202, 11548220, 11548226, 11548352, 11548366, 11548370, 11548396, 11549037, 11549043, 11549240, 11549342, 11549356, 11549494, 11549501, 11549602, 11549616, 11549617, 11549618, 11549733, 11549734, 11549942, 11550074, 11550908, 11551173, 11551502, 11551560, 11551577, 11552078, 11552140, 11552320, 11552430, 11552552, 11552561, 11552865, 11552912, 11553068, 11553074, 11553266, 11553272, 11553398, 11554054, 11554954, 11556338, 11556345, 11556447, 11558012, 11558027, 11558618, 11610053, 11610097, 11610103, 11610213, 11610270, 11610280, 11610286, 11610416, 11610422, 11610430, 11610431, 11610488, 11610564, 11610569, 11610576, 11610590, 11610604, 11610605, 11610610, 11610731, 11610749, 11610760, 11613402, 11613417, 11613418, 11613432, 11613446, 11613776, 11613818, 11613928, 11614055, 11616821, 11616882, 11616896, 11616897, 11617129, 11617146, 11617150, 11621828, 11621914, 11622192, 11622196, 11622225, 11622262, 11622378, 11622385, 11622399, 11622465, 11622509, 11622523, 11626923, 11626989, 11627005, 11627047, 11627204, 11627207, 11627218, 11627221, 11627227, 11627242, 11627280, 11627384, 11627420, 11630352, 11683267, 11683492, 11683496, 11684473, 11685192, 11685198, 11685736, 11685836, 11685864, 11685903, 11685923, 11685957, 11686932, 11687019, 11687112, 11687135, 11688388, 11688426, 11688672, 11689208, 11690269, 11690310, 11690326, 11690456, 11691623, 11691731, 11691745, 11691746, 11691752, 11691848, 11692470, 11692572, 11692586, 11692587, 11692593, 11692699, 11692703, 11692718, 11692743, 11692761, 11692790, 11692796, 11692877, 11693022, 11693164, 11693167, 11693327, 11693428, 11693666, 11693718, 11693747, 11694197, 11694217, 11694240, 11694269, 11694275, 11694309, 11694323, 11694327, 11694333, 11694443, 11694704, 11694710, 11694880, 11695041, 11695081, 11695095, 11695139, 11695145, 11695197, 11695237, 11695266, 11695284, 11695313, 11695371, 11695429, 11695435, 11695477, 11695488, 11695545, 11695589, 11695609, 11695951, 11696252, 11696276, 11696363, 11697503, 11697510, 11697564, 11697618, 11697632, 11697633, 11697677, 11697697, 11697744, 11697807, 11697813, 11697890, 11697894, 11697900, 11698050, 11698068, 11698074, 11698459, 11698473, 11698474, 11698480, 11698557, 11698591, 11698866, 11698909, 11699185, 11699246, 11699300, 11699315, 11699426, 11699601, 11699727, 11699746, 11699750, 11699756, 11700087, 11700172, 11700185, 11700316, 11700330, 11700345, 11700356, 11700360, 11700402, 11700461, 11700476, 11700533, 11700591, 11700597, 11701717, 11701837, 11701949, 11702084, 11702099, 11702105, 11702201, 11702664, 11702679, 11703782, 11703812, 11703912, 11704976, 11756381, 11756474, 11756592, 11756735, 11756746, 11756752, 11756756, 11756793, 11756895, 11756924, 11756938, 11756939, 11756944, 11756967, 11757020, 11757083, 11759844, 11759852, 11759862, 11759867, 11759970, 11760099, 11760105, 11760129, 11760152, 11760154, 11760186, 11760255, 11760259, 11760273, 11760274, 11760346, 11760424, 11760447, 11763108, 11763159, 11763165, 11763179, 11763188, 11763216, 11763218, 11763230, 11763231, 11763289, 11763427, 11763445, 11763449, 11763453, 11763459, 11763460, 11763463, 11763469, 11763484, 11763493, 11763516, 11763520, 11763623, 11763631, 11763637, 11763730, 11763826, 11768161, 11768176, 11768190, 11768248, 11768271, 11768306, 11768335, 11768388, 11768437, 11768466, 11768473, 11768505, 11768509, 11768524, 11768526, 11768530, 11768554, 11768596, 11768640, 11768669, 11768679, 11768683, 11768684, 11768689, 11768708, 11768726, 11768734, 11768756, 11768792, 11768799, 11768834, 11768852, 11773280, 11773570, 11773585, 11773740, 11776686, 11777079, 11781089, 11784705, 11786667, 11787905, 11792536, 11792540, 11792550, 11793025, 11801007, 11801363, 11830449, 11830511, 11830611, 11830627, 11831231, 11831351, 11831352, 11831508, 11831526, 11832170, 11832237, 11832257, 11832293, 11832482, 11832605, 11832657, 11833411, 11833886, 11833904, 11833910, 11834780, 11834825, 11835441, 11837112, 11837123, 11837964, 11838065, 11838079, 11838080, 11838086, 11838236, 11838544, 11838801, 11838805, 11838906, 11838920, 11838921, 11839029, 11839037, 11839077, 11839095, 11839114, 11839124, 11839130, 11839149, 11839240, 11839246, 11839313, 11839385, 11839646, 11839864, 11840081, 11840487, 11840531, 11840534, 11840643, 11840661, 11840738, 11840759, 11840777, 11840783, 11840870, 11841034, 11841038, 11841044, 11842155, 11842162, 11842169, 11842394, 11842610, 11842749, 11843920, 11843952, 11843953, 11843966, 11843967, 11844011, 11844078, 11844083, 11844170, 11844292, 11844344, 11844359, 11844379, 11844408, 11844808, 11844901, 11844997, 11845220, 11845750, 11845934, 11846074, 11846080, 11846084, 11846090, 11850688, 11889366, 11927661, 11931025, 11932149, 11932510, 11934215, 11936762, 11938144, 11938159, 11939101, 11939435, 11941400, 11941402, 11941523, 11941528, 11941623, 11941813, 11946554, 11946569, 11946671, 11946685, 11952231, 12051810, 12055870, 12056622, 12059989, 12061894, 12245309, 12403319, 12405128, 12536885, 12536922, 12536967, 12537009, 12537060, 12537066, 12537067, 12537111, 12537131, 12537140, 12537148, 12537213, 12537314, 12537320, 12537329, 12537346, 12537347, 12537357, 12537372, 12537386, 12537516, 12537531, 12537909, 12540194, 12540310, 12540314, 12540511, 12540533, 12540558, 12540687, 12540698, 12540722, 12540765, 12540890, 12540895, 12543610, 12543613, 12543650, 12543680, 12543695, 12543875, 12543883, 12543911, 12543922, 12543926, 12543936, 12543940, 12543941, 12543994, 12543998, 12544000, 12544042, 12544048, 12544057, 12544086, 12544100, 12544114, 12548594, 12548696, 12548828, 12548836, 12548842, 12548921, 12548978, 12549044, 12549142, 12549146, 12549149, 12551643, 12557556, 12757178, 12757196, 12757393, 12757394, 12757409, 12758119, 12758133, 12758134, 12758207, 12758308, 12758419, 12758858, 12759019, 12759081, 12759087, 12759096, 12759260, 12760686, 12761092, 12761382, 12761455, 12761483, 12761484, 12761497, 12761498, 12761538, 12761556, 12761562, 12761614, 12761962, 12762324, 12762793, 12763064, 12763151, 12763354, 12763369, 12763383, 12763427, 12763441, 12763447, 12763542, 12763572, 12763597, 12763615, 12763894, 12763905, 12764021, 12764847, 12764848, 12764861, 12764862, 12764960, 12764978, 12765326, 12765703, 12765912, 12766011, 12766421, 12766530, 12766539, 12766545, 12766646, 12766655, 12766660, 12766782, 12766863, 12767008, 12767269, 12767313, 12767316, 12767356, 12767362, 12767371, 12767425, 12767429, 12767433, 12767443, 12767449, 12767486, 12767501, 12767545, 12767559, 12767820, 12769052, 12769066, 12769067, 12769140, 12769168, 12769372, 12770734, 12770735, 12770748, 12770749, 12770923, 12771575, 12771589, 12771590, 12771909, 12771915, 12771982, 12772007, 12772308, 12772310, 12772315, 12772362, 12772416, 12772424, 12772430, 12772431, 12772500, 12772504, 12772518, 12772543, 12772717, 12772721, 12772872, 12772895, 12773533, 12773587, 12774098, 12774106, 12774108, 12774112, 12774113, 12774119, 12774224, 12774953, 12775065, 12775163, 12775215, 12775780, 12775795, 12776509, 12776520, 12776621, 12776825, 12776883, 12776921, 12777462, 12777463, 12777476, 12777477, 12777763, 12777766, 12853939, 12853951, 12853964, 12853979, 12853985, 12854124, 12854182, 12854205, 12854207, 12854211, 12854226, 12854240, 12854246, 12854269, 12854327, 12854379, 12854385, 12854415, 12854425, 12854429, 12854487, 12854732, 12854748, 12854833, 12854842, 12854950, 12854966, 12855136, 12855313, 12855314, 12855319, 12855567, 12855571, 12855618, 12855628, 12855638, 12855807, 12855864, 12855975, 12856107, 12856115, 12856121, 12856153, 12856155, 12856410, 12856502, 12856508, 12856516, 12856531, 12856629, 12856632, 12856644, 12856740, 12856816, 12856821, 12856826, 12856914, 12856978, 12857000, 12857097, 12857303, 12857329, 12857343, 12857349, 12857401, 12857407, 12857546, 12857552, 12857575, 12857633, 12857779, 12857851, 12858198, 12858206, 12858212, 12858238, 12858329, 12858330, 12858334, 12858422, 12858596, 12858931, 12858937, 12859054, 12859108, 12859156, 12859268, 12859297, 12859344, 12859517, 12859762, 12859889, 12860765, 12860771, 12860789, 12860900, 12860910, 12860989, 12860997, 12861098, 12861143, 12861215, 12861681, 12861862, 12862403, 12862418, 12862424, 12863148, 12863149, 12863260, 12863359, 12863361, 12863376, 12863458, 12863979, 12863981, 12864095, 12864101, 12864105, 12864217, 12864221, 12864818, 12864831, 12864912, 12864945, 12864947, 12864981, 12865045, 12865052, 12865188, 12865231, 12865362, 12865376, 12865891, 12866147, 12866513, 12866611, 12866616, 12866734, 12866808, 12866832, 12866913, 12866914, 12866942, 12866996, 12868184, 12868409, 12868764, 12869031, 12869034, 12869036, 12869132, 12869146, 12869147, 12869150, 12869247, 12869257, 12869263, 12869421, 12869440, 12869577, 12869581, 12869587, 12869610, 12869859, 12869876, 12869918, 12869981, 12870085, 12870089, 12870282, 12870360, 12870370, 12871220, 12871662, 12871670, 12871771, 12872119, 12872389, 12872399, 12872495, 12872511, 12872621, 12872795, 12872934, 12873351, 12875033, 12930423, 12930757, 12930997, 12933903, 12934121, 12934150, 12934160, 12938926, 12939164, 12939196, 12944210, 12947577, 12947606, 12947776, 12951535, 12951767, 12951826, 12951981, 12952289, 12952324, 12952405, 12953231, 12953245, 12953246, 12953252, 12953334, 12953357, 12953420, 12954086, 12954087, 12954131, 12954151, 12954187, 12955131, 12955160, 12955407, 12955768, 12955798, 12955958, 12956190, 12956204, 12956210, 12956668, 12956698, 12956711, 12956893, 12956899, 12960800, 12960815, 12960821, 12961004, 12961018, 12961526, 12961641, 12961655, 12961656, 12962120, 12962468, 12962474, 12962497, 12962555, 12962671, 12962889, 12963465, 12963773, 12964164, 12964174, 12964178, 12964179, 12964498, 12966697, 12966701, 12966702, 12966779, 12966876, 12966882, 12967021, 12967166, 12967543, 12967978, 12968158, 12969102, 12969109, 12969210, 12969218, 12969220, 12969224, 12969225, 12969226, 12971625, 12972473, 13097829, 13097869, 13098130, 13098159, 13098275, 13098281, 13098305, 13098319, 13098724, 13098739, 13098850, 13098928, 13099030, 13099754, 13099760, 13100406, 13100417, 13100421, 13102943, 13103394, 13103414, 13103785, 13104597, 13104829, 13104835, 13104887, 13105033, 13105343, 13105583, 13106293, 13106307, 13106308, 13107021, 13107149, 13107178, 13107352, 13107867, 13107869, 13107986, 13108309, 13108788, 13108798, 13108827, 13108831, 13108942, 13109078, 13109252, 13109266, 13109643, 13109701, 13109707, 13109846, 13109852, 13109904, 13109910, 13109933, 13109991, 13109997, 13110060, 13110064, 13110079, 13110107, 13110113, 13110498, 13110512, 13110513, 13110519, 13111804, 13112072, 13112195, 13112295, 13112296, 13112297, 13112630, 13112636, 13113021, 13113036, 13113037, 13113113, 13113147, 13113153, 13113461, 13113471, 13113754, 13113756, 13113761, 13113808, 13113862, 13113877, 13113993, 13114080, 13114162, 13114166, 13114167, 13114298, 13114713, 13114718, 13114762, 13114765, 13114907, 13114921, 13114950, 13114964, 13115037, 13115043, 13115110, 13115125, 13115139, 13115142, 13115153, 13115544, 13115554, 13115558, 13115559, 13116279, 13116377, 13116385, 13116393, 13116395, 13116399, 13116502, 13116618, 13116646, 13116824, 13116835, 13117959, 13118329, 13118489, 13118924, 13119040, 13244475, 13244947, 13244992, 13245066, 13245073, 13245184, 13245262, 13245266, 13245537, 13245987, 13246088, 13246639, 13246726, 13246732, 13246754, 13246755, 13246857, 13246871, 13247549, 13247973, 13248452, 13248466, 13249148, 13249155, 13249197, 13249342, 13251209, 13251221, 13252729, 13253468, 13253482, 13253483, 13254201, 13254219, 13254233, 13254319, 13254324, 13254533, 13254562, 13254613, 13254639, 13254643, 13254649, 13255096, 13255136, 13255150, 13255165, 13255223, 13255265, 13255432, 13255600, 13255606, 13256833, 13256945, 13258406, 13258848, 13258993, 13259254, 13259370, 13259463, 13259544, 13259584, 13259762, 13259805, 13259811, 13260298, 13260322, 13260652, 13341991, 13342514, 13350183, 13350198, 13352098, 13352692, 13353997, 13354402, 13463791, 13464451, 13464559, 13464573, 13464574, 13464643, 13464685, 13464689, 13464763, 13464865, 13464933, 13464937, 13465589, 13465774, 13466223, 13466256, 13466329, 13466345, 13466372, 13466465, 13466546, 13466847, 13467923, 13467937, 13467938, 13468663, 13468778, 13468779, 13468843, 13469069, 13469237, 13469603, 13469719, 13470389, 13470395, 13470403, 13470432, 13470501, 13470664, 13470693, 13470708, 13470722, 13470723, 13470838, 13470868, 13470885, 13471594, 13472017, 13472142, 13472143, 13472241, 13472259, 13472568, 13472853, 13472861, 13472869, 13472981, 13472984, 13472985, 13473084, 13473105, 13473173, 13473193, 13473206, 13473267, 13473269, 13473271, 13473285, 13473303, 13473372, 13473376, 13473415, 13473419, 13473425, 13473434, 13473449, 13473463, 13473473, 13473704, 13473709, 13473720, 13473734, 13473817, 13473818, 13473820, 13473825, 13473831, 13473856, 13474063, 13474134, 13474144, 13474637, 13474643, 13474651, 13474665, 13474666, 13474706, 13474713, 13474720, 13474724, 13474836, 13475054, 13475097, 13475101, 13475692, 13476227, 13476449, 13476637, 13476673, 13477909, 13478001, 13478015, 13478016, 13478074, 13478084, 13478141, 13478146, 13478204, 13478210, 13478305, 13478320, 13478494, 13478870, 13478982, 13479263, 13479283, 13479586, 13479602, 13479697, 13479706, 13479712, 13479823, 13479828, 13480291, 13480448, 13480481, 13480484, 13480501, 13480600, 13480756, 13480757, 13480796, 13480815, 13480936, 13480974, 13480988, 13481379, 13481393, 13481394, 13481400, 13481505, 13481683, 13481690, 13481893, 13482108, 13482220, 13482234, 13483076, 13483902, 13483916, 13484018, 13484164, 13484202, 13484324, 13484758, 13512424, 13512482, 13512527, 13512569, 13512620, 13512696, 13512699, 13512700, 13512707, 13512711, 13512720, 13512740, 13512743, 13512757, 13512772, 13512773, 13512812, 13512816, 13512820, 13512874, 13512875, 13512885, 13512888, 13512889, 13512894, 13512935, 13512946, 13512947, 13512960, 13512972, 13512974, 13513033, 13515760, 13516118, 13516121, 13516122, 13516137, 13518285, 13518626, 13518855, 13518902, 13519524, 13521872, 13524262, 13524301, 13524343, 13524473, 13524532, 13524563, 13524572, 13524576, 13524586, 13524590, 13524604, 13524648, 13524657, 13524720, 13524737, 13524746, 13524748, 13524750, 13524800, 13524804, 13524807, 13526675, 13526678, 13526680, 13526686, 13526687, 13526689, 13526693, 13526696, 13526698, 13526794, 13526812, 13526918, 13526921, 13527081, 13527083, 13527086, 13527102, 13527255, 13527266, 13529226, 13529292, 13529298, 13529302, 13529347, 13529361, 13529389, 13529526, 13529527, 13529531, 13529534, 13529563, 13529650, 13529695, 13529708, 13529723, 13529748, 13529752, 13529766, 13529947, 13532695, 13537475, 13540375, 13561492, 13561507, 13561521, 13561527, 13561550, 13561652, 13561666, 13561681, 13561724, 13561884, 13562588, 13562909, 13563058, 13563406, 13563691, 13563811, 13563812, 13563856, 13563876, 13564270, 13564624, 13564630, 13564827, 13564841, 13564856, 13564862, 13564885, 13564972, 13564978, 13565030, 13565493, 13566218, 13566586, 13566592, 13566618, 13566625, 13566785, 13567543, 13568046, 13568220, 13568264, 13568394, 13568409, 13568417, 13568458, 13568608, 13568612, 13569989, 13570134, 13570413, 13570540, 13570569, 13570725, 13570729, 13570743, 13571015, 13571367, 13571376, 13571381, 13571483, 13571497, 13572106, 13572193, 13572222, 13572270, 13572465, 13572511, 13572610, 13572614, 13572618, 13572643, 13572647, 13572657, 13572721, 13572813, 13573773, 13573781, 13574002, 13574004, 13574200, 13574321, 13574339, 13575454, 13575460, 13575463, 13575571, 13575585, 13575586, 13575615, 13575630, 13575686, 13575689, 13575697, 13575760, 13575871, 13575880, 13575945, 13575949, 13575963, 13575969, 13576044, 13576065, 13576075, 13576433, 13576815, 13577138, 13577145, 13577553, 13577703, 13577729, 13578945, 13578949, 13579670, 13579674, 13579776, 13579784, 13579786, 13579790, 13579902, 13580617, 13580631, 13580632, 13581063, 13581067, 13581468, 13634390, 13634413, 13634427, 13634433, 13634486, 13634514, 13634543, 13634616, 13634626, 13634631, 13634636, 13634645, 13634652, 13634653, 13634660, 13634665, 13634670, 13634674, 13634688, 13634702, 13634709, 13634717, 13634761, 13634775, 13634818, 13634819, 13634825, 13634828, 13634830, 13634851, 13634913, 13634935, 13634971, 13634972, 13634973, 13634978, 13635018, 13635036, 13636215, 13637691, 13637697, 13637703, 13637704, 13637791, 13637813, 13637815, 13637817, 13637819, 13637821, 13637928, 13638010, 13638016, 13638066, 13638082, 13638103, 13638182, 13638183, 13638193, 13638255, 13638295, 13638337, 13638342, 13641058, 13641169, 13641177, 13641183, 13641184, 13641214, 13641380, 13641393, 13641416, 13641431, 13641445, 13641451, 13641503, 13641547, 13641553, 13641560, 13641561, 13641562, 13641579, 13641586, 13641591, 13641605, 13641619, 13641647, 13641659, 13641663, 13641677, 13643704, 13644154, 13644540, 13644824, 13646107, 13646115, 13646164, 13646215, 13646229, 13646230, 13646278, 13646288, 13646365, 13646371, 13646415, 13646477, 13646512, 13646513, 13646515, 13646520, 13646521, 13646535, 13646549, 13646593, 13646600, 13646602, 13646608, 13646651, 13646665, 13646666, 13646695, 13646709, 13646737, 13646747, 13646752, 13646758, 13646812, 13648738, 13648757, 13648869, 13649047, 13649200, 13649205, 13650543, 13650893, 13650896, 13651210, 13651465, 13651474, 13651479, 13651537, 13651538, 13651635, 13651671, 13651697, 13651711, 13652842, 13654639, 13654849, 13659048, 13659599, 13660259, 13660527, 13660615, 13660697, 13660701, 13660707, 13661252, 13661353, 13661367, 13661368, 13661437, 13661441, 13661557, 13662253, 13662397, 13662470, 13662471, 13662633, 13662672, 13662688, 13663485, 13663978, 13663979, 13664000, 13664076, 13664105, 13664134, 13664152, 13666704, 13667139, 13667254, 13667255, 13668067, 13668081, 13668091, 13668095, 13668096, 13668101, 13668270, 13668276, 13668299, 13668305, 13668386, 13668531, 13668537, 13668571, 13668820, 13668821, 13668832, 13669053, 13669256, 13669394, 13669706, 13669749, 13669763, 13669778, 13669782, 13669822, 13669836, 13669865, 13669952, 13669958, 13670242, 13671445, 13671460, 13671785, 13673127, 13673128, 13673137, 13673142, 13673247, 13673258, 13673416, 13673501, 13673603, 13673860, 13673983, 13674395, 13674400, 13674418, 13674707, 13674809, 13674824, 13674897, 13674980, 13675285, 13675288, 13676505, 13678187, 13678188, 13678416, 13678609, 13678612, 13678629, 13679753, 13679855, 13679863, 13679869, 13679870, 13707681, 13707732, 13707811, 13707876, 13708001, 13708044, 13708058, 13708059, 13708145, 13710866, 13710870, 13710958, 13710972, 13710974, 13710982, 13710986, 13710987, 13710988, 13711033, 13711045, 13711098, 13711177, 13711183, 13711184, 13711187, 13711234, 13711248, 13711277, 13711306, 13711364, 13711422, 13711509, 13714322, 13714336, 13714350, 13714351, 13714357, 13714367, 13714547, 13714579, 13714580, 13714583, 13714604, 13714612, 13714613, 13714641, 13714670, 13714721, 13714727, 13714729, 13714772, 13714786, 13714830, 13714850, 13716984, 13719274, 13719374, 13719413, 13719455, 13719600, 13719680, 13719775, 13719792, 13721799, 13722028, 13724327, 13724501, 13724638, 13724639, 13724696, 13727792, 13727806, 13727813, 13727836, 13728170, 13728184, 13728228, 13731925, 13731946, 13731969, 13732012, 13732070, 13732113, 13732123, 13732172, 13732244, 13732259, 13732261, 13732265, 13732274, 13732331, 13732390, 13732407, 13732433, 13732447, 13732453, 13732491, 13732534, 13732592, 13735289, 13735347, 13735368, 13735373, 13735375, 13735377, 13735696, 13735898, 13735956, 13738668, 13738674, 13738697, 13738739, 13738949, 13738958, 13738972, 13738978, 13738989, 13738993, 13739045, 13739059, 13739060, 13739135, 13739175, 13739219, 13743665, 13743699, 13743786, 13743844, 13743887, 13743916, 13744014, 13744070, 13744073, 13744077, 13744091, 13744105, 13744111, 13744157, 13744181, 13744210, 13744221, 13744265, 13744308, 13744351, 13748728, 13748745, 13748817, 13748832, 13748848, 13748890, 13748949, 13749061, 13749064, 13749081, 13749085, 13749192, 13749206, 13749209, 13749224, 13749227, 13749267, 13752195, 13752405, 13752501, 13756277, 13756335, 13756509, 13756597, 13756598, 13756605, 13756772, 13756779, 13756796, 13756996, 13759648, 13759757, 13759765, 13759873, 13759925, 13759961, 13759969, 13760134, 13760287, 13763318, 13763498, 13763505, 13763506, 13763550, 13763553, 13763564, 13768109, 13768233, 13768335, 13768345, 13768355, 13768379, 13768464, 13768466, 13768538, 13768540, 13768596, 13768640, 13768698, 13773279, 13773598, 13773616, 13773656, 13780703, 13780724, 13780821, 13780848, 13780899, 13780950, 13780970, 13781109, 13781153, 13781154, 13781159, 13781160, 13781167, 13781173, 13781185, 13781214, 13781312, 13784023, 13784030, 13784035, 13784067, 13784088, 13784125, 13784139, 13784153, 13784154, 13784350, 13787431, 13787452, 13787475, 13787518, 13787534, 13787548, 13787576, 13787823, 13787837, 13787843, 13787896, 13787953, 13792477, 13792535, 13792556, 13792561, 13792622, 13792724, 13792839, 13792846, 13792847, 13792851, 13792855, 13792927, 13792942, 13792999, 13793029, 13793078, 13793086, 13793092, 13797523, 13797544, 13797626, 13797668, 13798005, 13800974, 13805353, 13805498, 13805504, 13805542, 13805556, 13805658, 13805904, 13806019, 13806745, 13806861, 13807031, 13807035, 13807050, 13807586, 13807679, 13807802, 13807986, 13809267, 13809268, 13809801, 13809819, 13809825, 13810224, 13810289, 13811529, 13812110, 13812116, 13812632, 13813212, 13813462, 13813584, 13813589, 13813872, 13814303, 13814309, 13814313, 13814314, 13814387, 13814391, 13814397, 13814401, 13814415, 13814420, 13814429, 13814430, 13814531, 13814715, 13814720, 13814888, 13814894, 13814909, 13815150, 13815155, 13815995, 13816040, 13816069, 13816112, 13816170, 13816212, 13816321, 13816359, 13816379, 13816504, 13816524, 13816959, 13817127, 13817133, 13817142, 13817156, 13817185, 13817272, 13817374, 13817377, 13817388, 13817394, 13817881, 13817887, 13818083, 13818119, 13818153, 13819353, 13819360, 13819385, 13819389, 13819429, 13819443, 13819447, 13819461, 13819462, 13819469, 13819475, 13819476, 13819481, 13819530, 13819737, 13819835, 13819859, 13819893, 13819911, 13819940, 13821245, 13821441, 13821927, 13821942, 13822046, 13822173, 13822202, 13822231, 13822245, 13822318, 13822324, 13822376, 13822391, 13822423, 13822434, 13823564, 13823609, 13823666, 13823680, 13823697, 13823710, 13823870, 13823884, 13823913, 13823927, 13823928, 13824000, 13824073, 13824105, 13825247, 13825552, 13825834, 13826315, 13829496, 13829516, 13829525, 13829535, 13829553, 13829567, 13829568, 13829626, 13829645, 13829677, 13829679, 13829685, 13829728, 13829756, 13829757, 13829764, 13829772, 13829800, 13829815, 13829817, 13829821, 13829829, 13829930, 13829931, 13829937, 13829945, 13829946, 13829968, 13829989, 13830003, 13830025, 13830083, 13830085, 13830090, 13830133, 13830148, 13830159, 13830184, 13832808, 13832809, 13832811, 13832815, 13832817, 13832866, 13832874, 13832875, 13832917, 13832925, 13832931, 13833041, 13833047, 13833106, 13833120, 13833127, 13833128, 13833136, 13833178, 13833194, 13833222, 13833303, 13833367, 13833384, 13833454, 13833460, 13836173, 13836220, 13836224, 13836230, 13836238, 13836244, 13836253, 13836281, 13836289, 13836295, 13836296, 13836312, 13836325, 13836456, 13836470, 13836476, 13836485, 13836492, 13836500, 13836528, 13836539, 13836543, 13836549, 13836557, 13836563, 13836659, 13836674, 13836679, 13836717, 13836731, 13836738, 13836789, 13838704, 13838709, 13838936, 13839097, 13841299, 13841313, 13841451, 13841502, 13841619, 13841624, 13841625, 13841626, 13841627, 13841631, 13841633, 13841705, 13841711, 13841720, 13841737, 13841777, 13841807, 13843734, 13843737, 13843750, 13843755, 13843850, 13843851, 13843869, 13843969, 13843982, 13844140, 13844143, 13844315, 13844317, 13846359, 13846373, 13846387, 13846388, 13846446, 13846577, 13846606, 13846626, 13846641, 13846760, 13846765, 13846766, 13846805, 13849629, 13850115, 13870644, 13870646, 13870662, 13870665, 13870765, 13870876, 13870878, 13870894, 13870900, 13927037, 13927058, 13927081, 13927140, 13927168, 13927182, 13927188, 13927225, 13927228, 13927235, 13927239, 13927284, 13927313, 13927319, 13927320, 13927353, 13927370, 13927371, 13927373, 13927377, 13927432, 13927443, 13927487, 13927493, 13927496, 13927500, 13927501, 13927502, 13927519, 13927545, 13927559, 13927583, 13927603, 13927646, 13927704, 13930357, 13930364, 13930365, 13930366, 13930367, 13930371, 13930444, 13930445, 13930459, 13930473, 13930480, 13930481, 13930482, 13930483, 13930485, 13930487, 13930597, 13930599, 13930673, 13930677, 13930684, 13930706, 13930720, 13930734, 13930745, 13930749, 13930750, 13930807, 13930808, 13930851, 13930860, 13930923, 13931010, 13931016, 13931068, 13933783, 13933786, 13933837, 13933852, 13933868, 13934038, 13934041, 13934048, 13934056, 13934070, 13934084, 13934090, 13934101, 13934105, 13934114, 13934153, 13934157, 13934215, 13934221, 13934226, 13934229, 13934331, 13934351, 13938768, 13938772, 13938782, 13938783, 13938811, 13938832, 13938895, 13938962, 13938998, 13939015, 13939058, 13939087, 13939096, 13939112, 13939127, 13939144, 13939145, 13939147, 13939151, 13939154, 13939165, 13939173, 13939180, 13939181, 13939185, 13939187, 13939203, 13939217, 13939218, 13939261, 13939267, 13939269, 13939281, 13939304, 13939333, 13939334, 13939357, 13939359, 13939361, 13939363, 13939377, 13939415, 13939417, 13939420, 13941290, 13941291, 13941293, 13941297, 13941302, 13941309, 13941311, 13941418, 13941425, 13941519, 13941525, 13941531, 13941534, 13941708, 13941715, 13941717, 13941879, 13941886, 13943857, 13943878, 13943901, 13943915, 13943921, 13943960, 13944140, 13944141, 13944170, 13944173, 13944176, 13944193, 13944197, 13944205, 13944206, 13944245, 13944249, 13944307, 13944322, 13947293, 13947308, 13947497, 13947501, 13947507, 13947526, 13947546, 13947671, 13947729, 13951992, 13952227, 13952238, 13952273, 13952353, 13952423, 13953127, 13953369, 13953375, 13953558, 13954007, 13954325, 13954448, 13954834, 13955139, 13955254, 13955298, 13955312, 13955318, 13956438, 13956442, 13956646, 13956849, 13957863, 13958212, 13958490, 13958503, 13958662, 13958676, 13959068, 13960010, 13960379, 13960696, 13960775, 13960789, 13960938, 13960944, 13960973, 13961072, 13961228, 13961473, 13961721, 13961819, 13961895, 13961913, 13961924, 13962562, 13962620, 13962689, 13962707, 13962713, 13962907, 13964127, 13964128, 13964487, 13965683, 13965687, 13965694, 13965695, 13965781, 13965795, 13965809, 13965810, 13965839, 13965845, 13965854, 13965874, 13966019, 13966169, 13966636, 13966651, 13966728, 13967115, 13967781, 13967851, 13967909, 14000204, 14000225, 14000262, 14000335, 14000349, 14000400, 14000402, 14000480, 14000500, 14000513, 14000520, 14000529, 14000540, 14000544, 14000552, 14000610, 14000611, 14000653, 14000654, 14000655, 14000662, 14000669, 14000686, 14000715, 14000726, 14000864, 14000871, 14003532, 14003538, 14003540, 14003612, 14003640, 14003648, 14003652, 14003654, 14003655, 14003656, 14003770, 14003851, 14003859, 14003975, 14004019, 14004235, 14007019, 14007049, 14007077, 14007208, 14007215, 14007251, 14007266, 14007268, 14007272, 14007324, 14007382, 14007389, 14007414, 14007425, 14007443, 14007454, 14007498, 14007635, 14011978, 14012254, 14012263, 14012350, 14012352, 14012384, 14012385, 14012427, 14012428, 14012437, 14012500, 14012524, 14017360, 14049809, 14050019, 14050186, 14050204, 14050737, 14050751, 14051036, 14052694, 14057478, 14057479, 14058436, 14058628, 14058795, 14059044, 14059933, 14059944, 14059973, 14059987, 14060002, 14060277, 14060426, 14061669, 14061684, 14061771, 14063239, 14063361, 14063827, 14064494, 14065121, 14066729, 14171217, 14171431, 14171774, 14171854, 14171855, 14171956, 14171971, 14172040, 14172044, 14172141, 14172144, 14172314, 14172319, 14172575, 14172696, 14172697, 14172769, 14172779, 14172783, 14172813, 14172870, 14172876, 14172986, 14173131, 14174581, 14175254, 14175317, 14176124, 14176274, 14176785, 14177278, 14177782, 14177800, 14177912, 14177945, 14178003, 14178004, 14178101, 14178137, 14178177, 14178583, 14179308, 14179419, 14179423, 14179424, 14179501, 14179535, 14179540, 14179604, 14180141, 14180147, 14180148, 14180149, 14180207, 14180213, 14180247, 14180264, 14180265, 14180439, 14180445, 14180468, 14180474, 14180479, 14180550, 14180566, 14180721, 14180729, 14180983, 14181091, 14181105, 14181106, 14181217, 14181222, 14181344, 14181425, 14181831, 14181987, 14181995, 14182001, 14182005, 14182011, 14182034, 14182103, 14182121, 14182127, 14182190, 14182359, 14182372, 14182378, 14182388, 14183513, 14183614, 14183624, 14183628, 14183629, 14183630, 14183744, 14183745, 14183930, 14184093, 14185195, 14185278, 14185288, 14185296, 14185311, 14185351, 14185355, 14185572, 14185585, 14185593, 14185601, 14185602, 14185630, 14185674, 14185694, 14185707, 14185746, 14185752, 14186035, 14186137, 14186151, 14186152, 14186326, 14186540, 14186544, 14186554, 14186573, 14186593, 14186876, 14186992, 14186993, 14187080, 14187095, 14187285, 14187428, 14187434, 14187457, 14187733, 14188022, 14188096, 14188119, 14188139, 14188269, 14188548, 14188559, 14188674, 14189390, 14189400, 14189501, 14189515, 14189618, 14189783, 14189908, 14189951, 14190342, 14190356, 14190357, 14190473, 14190640, 14190642, 14190646, 14190821, 14191557, 14192630, 14192634, 14268501, 14268527, 14268541, 14268555, 14268744, 14268773, 14268802, 14268947, 14268953, 14268962, 14269005, 14269165, 14270339, 14270345, 14270669, 14271070, 14272137, 14272166, 14272180, 14272181, 14272195, 14272201, 14272311, 14272358, 14272804, 14273674, 14274062, 14274080, 14274834, 14275399, 14275501, 14275544, 14275559, 14277691, 14277759, 14277821, 14277826, 14277919, 14277921, 14278024, 14278030, 14278101, 14278111, 14278647, 14278662, 14278971, 14278977, 14278981, 14278982, 14279054, 14279434, 14279474, 14279476, 14279503, 14279504, 14279547, 14279601, 14279750, 14279760, 14279770, 14279788, 14279792, 14279895, 14279927, 14279938, 14279944, 14279953, 14281062, 14281069, 14281171, 14281286, 14281481, 14282741, 14282751, 14282795, 14282859, 14282867, 14282882, 14282896, 14282907, 14283099, 14283114, 14283128, 14283226, 14283250, 14283259, 14283302, 14283893, 14284125, 14284129, 14284143, 14284149, 14284549, 14284636, 14284908, 14284916, 14285321, 14285437, 14287057, 14287065, 14287189, 14287797, 14287913, 14288348, 14341671, 14341785, 14341847, 14341926, 14341930, 14341980, 14341984, 14341986, 14341990, 14341998, 14342003, 14342108, 14342114, 14342259, 14342265, 14345000, 14345025, 14345086, 14345093, 14345101, 14345131, 14345154, 14345201, 14345212, 14345216, 14345291, 14345315, 14345319, 14345362, 14345395, 14345464, 14345472, 14345493, 14345504, 14345518, 14345522, 14345623, 14345629, 14348399, 14348452, 14348512, 14348523, 14348660, 14348661, 14348712, 14348714, 14348718, 14348726, 14348748, 14348827, 14348835, 14353541, 14353700, 14353704, 14353760, 14353883, 14353884, 14353885, 14353903, 14353975, 14354026, 14355904, 14355922, 14356020, 14356038, 14356135, 14356147, 14356307, 14356310, 14356328, 14356492, 14358528, 14358615, 14358684, 14358772, 14358786, 14358810, 14358927, 14359119, 14362139, 14362143, 14362159, 14366387, 14366485, 14366503, 14367065, 14367685, 14367732, 14367793, 14367808, 14367901, 14367982, 14367988, 14368526, 14368626, 14368634, 14368649, 14369867, 14370360, 14370621, 14370766, 14371171, 14371172, 14371260, 14371457, 14374420, 14374536, 14374652, 14374692, 14375253, 14375334, 14375362, 14375376, 14375377, 14375580, 14375586, 14375801, 14375812, 14376127, 14376203, 14376217, 14376218, 14376319, 14376503, 14376537, 14376943, 14377002, 14377030, 14377041, 14377044, 14377059, 14377103, 14377117, 14377233, 14377326, 14377494, 14377500, 14378857, 14379066, 14379153, 14380408, 14380423, 14380626, 14380713, 14380800, 14381984, 14382036, 14382104, 14382178, 14382221, 14382294, 14382468, 14382546, 14382566, 14386421, 14386571, 14387151, 14387766, 14512431, 14512437, 14512867, 14513055, 14514316, 14514322, 14514432, 14514873, 14514940, 14514960, 14515708, 14516694, 14516700, 14517100, 14517129, 14517376, 14517390, 14517491, 14517506, 14517956, 14518637, 14519377, 14519391, 14519437, 14519580, 14519623, 14520760, 14520855, 14520865, 14520869, 14520870, 14521160, 14521590, 14521594, 14521595, 14521696, 14521711, 14521914, 14521920, 14522146, 14522175, 14522284, 14522431, 14522465, 14522552, 14522650, 14522790, 14522842, 14523270, 14523354, 14523360, 14523378, 14523392, 14523393, 14523399, 14523433, 14523451, 14523493, 14523549, 14523567, 14523640, 14523857, 14524437, 14524466, 14524495, 14524658, 14524669, 14524966, 14525060, 14525061, 14525067, 14525074, 14525075, 14525148, 14525152, 14525162, 14525175, 14525191, 14525355, 14525365, 14525400, 14526366, 14526630, 14526636, 14526641, 14526688, 14526699, 14526757, 14526857, 14526863, 14527047, 14527048, 14527134, 14527149, 14527153, 14527155, 14527159, 14527174, 14527178, 14527192, 14527221, 14527482, 14527583, 14527598, 14527691, 14528010, 14528316, 14528424, 14528433, 14528438, 14528439, 14528880, 14529211, 14529327, 14529454, 14529541, 14529657, 14529672, 14529692, 14529715, 14529875, 14529895, 14530947, 14530955, 14530961, 14531064, 14531223, 14531229, 14531354, 14531374, 14531397, 14531557, 14531577, 14531903, 14531914, 14532629, 14532643, 14532929, 14532934, 14533470, 14533485, 14533587, 14585540, 14585555, 14585561, 14585570, 14585584, 14585598, 14585675, 14585735, 14585736, 14585738, 14585744, 14585856, 14585859, 14585880, 14585886, 14585888, 14585889, 14585932, 14585946, 14585947, 14586022, 14586033, 14586062, 14586063, 14586106, 14586142, 14586194, 14586207, 14588860, 14588876, 14588925, 14588976, 14588986, 14588991, 14589002, 14589094, 14589165, 14589205, 14589223, 14589237, 14589397, 14589398, 14589426, 14589513, 14589558, 14589571, 14592223, 14592224, 14592232, 14592254, 14592283, 14592289, 14592312, 14592340, 14592354, 14592355, 14592371, 14592409, 14592413, 14592515, 14592543, 14592551, 14592587, 14592602, 14592616, 14592638, 14592644, 14592645, 14592660, 14592674, 14592680, 14592762, 14592776, 14597270, 14597277, 14597286, 14597335, 14597358, 14597372, 14597459, 14597517, 14597561, 14597597, 14597630, 14597644, 14597646, 14597648, 14597654, 14597662, 14597688, 14597698, 14597706, 14597825, 14597836, 14597866, 14597880, 14597923, 14597981, 14602375, 14602381, 14602432, 14602446, 14602447, 14602501, 14602505, 14602643, 14602656, 14602676, 14602700, 14602853, 14602854, 14602871, 14605810, 14605971, 14606025, 14606130, 14606218, 14609973, 14609977, 14610393, 14618411, 14626731, 14626745, 14659055, 14659172, 14659218, 14659505, 14659512, 14659518, 14659519, 14659554, 14659620, 14659634, 14659635, 14660650, 14662883, 14663014, 14663028, 14663434, 14663440, 14664199, 14665711, 14665765, 14665922, 14667641, 14668045, 14668158, 14668234, 14668346, 14668441, 14668987, 14669124, 14669175, 14669205, 14669211, 14669611, 14669698, 14669712, 14669726, 14669727, 14669785, 14669827, 14669994, 14671401, 14671517, 14671598, 14672700, 14673000, 14673062, 14673120, 14673126, 14673265, 14673309, 14673323, 14673352, 14673353, 14673410, 14673468, 14674025, 14674106, 14674112, 14674759, 14675062, 14675198, 14675214, 14675237, 14678137, 14678977, 14707572, 14707683, 14707775, 14707821, 14707825, 14708007, 14708152, 14710812, 14710921, 14710929, 14710931, 14710933, 14710935, 14710937, 14711305, 14711310, 14714228, 14714299, 14714358, 14714496, 14714504, 14714547, 14714553, 14714561, 14714567, 14714663, 14714670, 14714674, 14714707, 14714721, 14714735, 14714852, 14719345, 14727755, 14759714, 14760091, 14763327, 14763653, 14764760, 14765600, 14766761, 14767240, 14767268, 14767283, 14768842, 14768950, 14768965, 14768971, 14769065, 14769255, 14769284, 14769396, 14770526, 14770632, 14770647, 14770937, 14772402, 14781116, 14951665, 14953984, 14963497, 15024822, 15029660, 15058148, 15066217, 15122577, 15146788, 15146835, 15148255, 15150152, 15156777, 15157376, 15157377, 15157390, 15157391, 15159068, 15162912, 15164119, 15166860, 15167701, 15171185, 15171797, 15171803, 15171993, 15172094, 15172163, 15172167, 15177559, 15177567, 15177573, 15180928, 15180939, 15181040, 15183563, 15183665, 15183680, 15184019, 15185133, 15185144, 15195468, 15195672, 15195723, 15195735, 15195816, 15196065, 15196072, 15196076, 15196178, 15196294, 15196488, 15196915, 15197034, 15197132, 15197223, 15197324, 15197330, 15197498, 15197499, 15197753, 15197861, 15197874, 15197976, 15198165, 15198325, 15198327, 15199140, 15199542, 15199674, 15199678, 15200270, 15200399, 15200673, 15200689, 15200856, 15201124, 15201352, 15202004, 15202115, 15202365, 15202501, 15202515, 15202521, 15202799, 15203747, 15203763, 15203863, 15204491, 15204493, 15204588, 15204604, 15204704, 15204720, 15205323, 15205445, 15205448, 15205565, 15207857, 15207955, 15207962, 15208078, 15209634, 15209750, 15209753, 15209762, 15210378, 15210380, 15210476, 15210491, 15210494, 15210495, 15210765, 15211220, 15211433, 15211440, 15211442, 15213839, 15213855, 15213971, 15214139, 15216490, 15245265, 15245537, 15246943, 15247036, 15247740, 15251700, 15253271, 15253366, 15253498, 15256730, 15256744, 15258306, 15258886, 15259254, 15259269, 15259274, 15259985, 15260108, 15260211, 15292898, 15293101, 15293198, 15293271, 15293275, 15293300, 15293329, 15293628, 15293754, 15293847, 15295417, 15296149, 15296156, 15296239, 15296372, 15296515, 15296534, 15297939, 15297953, 15297954, 15299513, 15299883, 15300057, 15302879, 15303718, 15303826, 15305508, 15307916, 15308147, 15308331, 15309643, 15310453, 15317341, 15321038, 15323183, 15324274, 15328212, 15331203, 15333994, 15334480, 15334491, 15334500, 15345417, 15348603, 15357664, 15393705, 15393712, 15407469, 15587704, 15588084, 15588099, 15590499, 15591072, 15591086, 15592507, 15592667, 15592681, 15592687, 15593870, 15594095, 15594827, 15594828, 15594928, 15594943, 15595116, 15595219, 15595277, 15595306, 15595987, 15595993, 15596462, 15596494, 15596509, 15596567, 15596944, 15598176, 15598190, 15598191, 15598292, 15598480, 15599752, 15599757, 15599850, 15599857, 15599873, 15599971, 15599994, 15600116, 15600337, 15600352, 15600591, 15600714, 15601149, 15601155, 15601655, 15602019, 15602327, 15602600, 15602658, 15602817, 15602831, 15604499, 15604919, 15683321, 15683335, 15683341, 15686739, 15686815, 15686873, 15686879, 15688057, 15688062, 15688178, 15688280, 15688468, 15689237, 15690063, 15690069, 15690121, 15690127, 15692266, 15692354, 15692355, 15692383, 15692586, 15692841, 15693935, 15693942, 15694036, 15694038, 15694071, 15694109, 15694176, 15694349, 15694500, 15694515, 15694529, 15697342, 15697473, 15697788, 15697812, 15697821, 15697831, 15697836, 15697864, 15697866, 15697908, 15698571, 15698687, 15698705, 15699042, 15702460, 15702474, 15702475, 15702584, 15704272, 15780891, 15780934, 15781529, 15781534, 15781535, 15782253, 15782355, 15782370, 15782463, 15783207, 15784429, 15784574, 15784580, 15784846, 15784922, 15785618, 15785832, 15785843, 15786023, 15789896, 15789938, 15790124, 15790132, 15790142, 15790775, 15791498, 15791505, 15791545, 15791606, 15791620, 15791621, 15791625, 15791721, 15791911, 15792056, 15792194, 15792647, 15792650, 15792665, 15793302, 15794862, 15794970, 15794981, 15794985, 15795025, 15795029, 15795086, 15795188, 15795422, 15795449, 15795710, 15795811, 15795826, 15795928, 15795943, 15796073, 15796111, 15796115, 15796243, 15796257, 15796537, 15796544, 15796551, 15796672, 15797040, 15797084, 15798233, 15798234, 15798302, 15798348, 15798349, 15798450, 15800026, 15801828, 15802002, 15857335, 15860583, 15865687, 15927399, 15928571, 15928581, 15928704, 15929522, 15929701, 15929837, 15930004, 15930600, 15932170, 15932518, 15933678, 15933721, 15933935, 15933939, 15934069, 15936143, 15936156, 15936258, 15936273, 15936274, 15936447, 15936690, 15936694, 15936698, 15936708, 15936748, 15936752, 15937113, 15937231, 15942986, 15943000, 15943001, 15943773, 15943886, 15944047, 15945509, 15945517, 15945523, 15946358, 15948119, 16000289, 16007120, 16012247, 16012427, 16012431, 16012485, 16078460, 16078466, 16078489, 16078495, 16082661, 16082868, 16083082, 16089776, 16177889, 16292799, 16294699, 16294709, 16294713, 16294719, 16295351, 16295357, 16295365, 16295379, 16295380, 16295424, 16295496, 16295815, 16297061, 16297091, 16297497, 16297888, 16297902, 16297903, 16297943, 16298188, 16298485, 16299030, 16299542, 16299614, 16299615, 16299788, 16299846, 16299962, 16299992, 16300006, 16300020, 16300064, 16301151, 16301252, 16301262, 16301266, 16301267, 16301296, 16302075, 16302079, 16302093, 16302107, 16302108, 16302210, 16302224, 16302282, 16302293, 16302311, 16302317, 16302549, 16302550, 16302934, 16302949, 16303413, 16303761, 16303775, 16303790, 16303796, 16303848, 16303964, 16303970, 16303979, 16303999, 16304033, 16304225, 16304231, 16304385, 16306342, 16307067, 16307073, 16307125, 16307139, 16307154, 16307161, 16307328, 16307386, 16307415, 16307473, 16307517, 16307561, 16307589, 16307595, 16307995, 16308088, 16308227, 16308314, 16308320, 16308416, 16308430, 16308717, 16308767, 16308835, 16309271, 16309693, 16309724, 16309851, 16309872, 16309880, 16310054, 16310503, 16310517, 16310518, 16310524, 16311243, 16311359, 16311562, 16312185, 16312195, 16312199, 16312200, 16313026, 16313997, 16314168, 16390601, 16390616, 16390628, 16390674, 16390790, 16390796, 16390892, 16391173, 16391253, 16392183, 16392929, 16392980, 16393000, 16393748, 16393754, 16393937, 16393951, 16393980, 16394009, 16394038, 16394044, 16394096, 16394102, 16394184, 16394198, 16394212, 16395039, 16395053, 16397112, 16397188, 16397344, 16397503, 16397518, 16397533, 16397736, 16398707, 16399595, 16399635, 16399824, 16399838, 16399839, 16399867, 16399873, 16400059, 16400099, 16400418, 16401277, 16401317, 16401390, 16401613, 16401781, 16405000, 16405105, 16405986, 16406276, 16406601, 16409741, 16409756, 16463514, 16463769, 16463808, 16463812, 16463829, 16463899, 16463943, 16463944, 16463997, 16464015, 16464041, 16464043, 16466821, 16466827, 16466829, 16466937, 16466939, 16466941, 16466974, 16467139, 16467141, 16467144, 16467205, 16467307, 16467379, 16467466, 16467524, 16470239, 16470279, 16470324, 16470338, 16470366, 16470497, 16470503, 16470540, 16470555, 16470569, 16470575, 16470598, 16470627, 16470628, 16470678, 16470684, 16470685, 16470703, 16470706, 16470714, 16470715, 16470729, 16470743, 16470749, 16470924, 16475543, 16475583, 16475631, 16475641, 16475645, 16475673, 16475717, 16475724, 16475732, 16475746, 16475756, 16475876, 16480629, 16480719, 16483717, 16489575, 16489825, 16489831, 16490469, 16490487, 16490491, 16490492, 16490786, 16490916, 16491521, 16491536, 16492159, 16492174, 16492180, 16492276, 16492615, 16492894, 16493055, 16493079, 16493103, 16496495, 16497158, 16497191, 16497205, 16497220, 16497260, 16497336, 16497347, 16497423, 16497429, 16497655, 16498380, 16498386, 16498830, 16498833, 16498873, 16498879, 16498887, 16498902, 16498908, 16498946, 16498960, 16498966, 16499076, 16499145, 16499163, 16499192, 16499497, 16500569, 16500583, 16500584, 16502251, 16502265, 16502266, 16502625, 16503092, 16503106, 16503107, 16503176, 16503195, 16503426, 16503432, 16503542, 16503827, 16503879, 16503933, 16503948, 16504021, 16504035, 16504041, 16504389, 16505615, 16505629, 16505630, 16508989, 16508994, 16634274, 16634491, 16636159, 16636870, 16638965, 16642336, 16642708, 16642712, 16642869, 16643525, 16643583, 16643763, 16644033, 16644274, 16644308, 16644314, 16644390, 16644970, 16645276, 16645284, 16645410, 16645416, 16645671, 16646048, 16646054, 16646062, 16646076, 16646106, 16646112, 16646265, 16646280, 16646286, 16646338, 16646396, 16646402, 16646469, 16646472, 16646519, 16646692, 16648571, 16648577, 16648774, 16648788, 16648832, 16648838, 16648983, 16648992, 16649007, 16649024, 16649035, 16651311, 16651369, 16651959, 16652790, 16652791, 16653641, 16707404, 16707719, 16707789, 16707790, 16707877, 16707891, 16707894, 16707985, 16710710, 16710768, 16711095, 16711241, 16714132, 16714183, 16714197, 16714198, 16714447, 16714605, 16714619, 16714633, 16719404, 16719493, 16719535, 16719665, 16719668, 16724539, 16727653, 16727863, 16783137, 16783624, 16790091, 16795341, 16795775, 16795864, 16796187, 16800078, 16887429, 16887444, 16889097, 16889387, 16894157, 16894172, 17098519, 17108725, 17108727, 17108912, 17112091, 17112286, 17112455, 17341827, 17415331, 17594604, 17594719, 17594825, 17595008, 17595298, 17601037, 17601047, 17707796, 17708144, 17708155, 17708179, 17709025, 17709189, 17709257, 17709275, 17711653, 17712464, 17712465, 17712581, 17713592, 17714509, 17715985, 17716123, 17716670, 17717506, 17717836, 17717970, 17718410, 17718526, 17720316, 17720319, 17720353, 17720359, 17721592, 17721654, 17721687, 17721715, 17721727, 17721890, 17721977, 17722093, 17722133, 17722147, 17723398, 17724210, 17724427, 17724558, 17725906, 17725927, 17726124, 17726182, 17726188, 17726240, 17743945, 17765317, 17765433, 17768217, 17794752, 17794868, 17804946, 17805062, 17805172, 17805236, 17805337, 17805347, 17805352, 17805382, 17806588, 17806744, 17806750, 17807498, 17808310, 17808734, 17810079, 17814400, 17814429, 17814458, 17814545, 17814661, 17816325, 17816329, 17819167, 17819243, 17819446, 17820113, 17820287, 17820548, 17820554, 17821070, 17821389, 17821969, 17821975, 17822631, 17822636, 17823472, 17824303, 17824318, 17833091, 17906083, 17910926, 17910941, 17911985, 17913392, 17915146, 17915471, 17916799, 17917002, 17917205, 17917252, 17918510, 17918612, 17979294, 17982586, 17984108, 18012065, 18012702, 18049615, 18049625, 18050721, 18051381, 18051475, 18051577, 18053795, 18053897, 18053998, 18054011, 18054022, 18054027, 18055527, 18056313, 18057260, 18057268, 18057274, 18057275, 18057732, 18058290, 18058296, 18059067, 18059276, 18059898, 18059914, 18059972, 18060668, 18060958, 18063162, 18063336, 18063481, 18065859, 18065934, 18066057, 18066062, 18066063, 18066077, 18066109, 18067352, 18067367, 18067469, 18067570, 18067958, 18122352, 18125658, 18129238, 18132954, 18146639, 18182991, 18195460, 18196765, 18196805, 18196812, 18197051, 18197055, 18197061, 18199839, 18200129, 18203594, 18203609, 18203789, 18203899, 18204329, 18204450, 18205519, 18205529, 18205610, 18208099, 18208139, 18209913, 18209931, 18216688, 18415900, 18416556, 18421381, 18421405, 18421791, 18422995, 18424671, 18424701, 18424890, 18425760, 18426054, 18426224, 18426228, 18427640, 18429026, 18429113, 18430795, 18434043, 18434995, 18435720, 18512459, 18513081, 18513456, 18513544, 18513561, 18514663, 18519245, 18523156, 18526506, 18526727, 18526843, 18527829, 18527835, 18528119, 18531599, 18533281, 18597425, 18610654, 18614046, 18614737, 18618947, 18618994, 18619248, 18619266, 18619807, 18619817, 18620683, 18620687, 18620693, 18620728, 18620745, 18620803, 18620809, 18620905, 18620925, 18622742, 18622752, 18623987, 18625907, 18630830, 18630954, 18695174, 18695194, 18756128, 18757277, 18757451, 18757766, 18759380, 18759387, 18759394, 18763274, 18763281, 18766141, 18766949, 18766956, 18767032, 18767064, 18767078, 18767079, 18767253, 18767902, 18767926, 18768021, 18768094, 18768152, 18768181, 18768361, 18769062, 18769076, 18770552, 18770559, 18770816, 18773140, 18774634, 18774750, 18912195, 18914974, 18914979, 18915396, 18916806, 18923615, 19004959, 19005367, 19010128, 19010228]
+}
+
+pub fn get_answers() -> Vec<u32> {
+    vec![769303, 769317, 774276, 797721, 798694, 816051, 817936, 822866, 870598, 879120, 882635, 893292, 906631, 1001325, 1002253, 1004696, 1007842, 1008161, 1010499, 1012975, 1012977, 1016932, 1026183, 1028953, 1037366, 1054771, 1054777, 1054967, 1055161, 1058331, 1098888, 1111314, 1111334, 1113092, 1150895, 1152784, 1154408, 1157669, 1159276, 1161879, 1178362, 1186816, 1223090, 1256719, 1269393, 1269595, 1269596, 1273895, 1441923, 1442523, 1442688, 1448365, 1455194, 1455205, 1455210, 1455866, 1537443, 1537874, 1537919, 1542437, 1542669, 1544489, 1546972, 1547057, 1548376, 1552192, 1649789, 1708169, 1708192, 1708453, 1708481, 1708488, 1708642, 1708830, 1711475, 1711845, 1712005, 1715209, 1715216, 1719943, 1720293, 1720526, 1725071, 1725317, 1725450, 1781764, 1781809, 1792799, 1793583, 1793600, 1795684, 1798468, 1854680, 1854729, 1854815, 1854975, 1855048, 1857802, 1857809, 1857918, 1861231, 1861254, 1861284, 1861546, 1861695, 1866212, 1866503, 1866625, 1866627, 1866860, 1871784, 1934652, 1934668, 1938600, 1942440, 1947919, 2148189, 2148267, 2157322, 2158047, 2158147, 2158446, 2161588, 2161917, 2163147, 2164449, 2245185, 2318071, 2318075, 2318155, 2318331, 2318338, 2318352, 2321205, 2321258, 2321316, 2321323, 2321730, 2321731, 2324622, 2324675, 2324876, 2324884, 2329849, 2330096, 2330126, 2335033, 2344871, 2347278, 2360110, 2360119, 2415626, 2415911, 2415922, 2418759, 2418763, 2419249, 2422178, 2422260, 2422459, 2422493, 2427224, 2427682, 2427714, 2427739, 2427807, 2488614, 2489090, 2490637, 2498223, 2498776, 2498875, 2502857, 2505434, 2505550, 2505691, 2505765, 2505881, 2506343, 2561784, 2561880, 2562097, 2562245, 2562271, 2562365, 2562465, 2565092, 2565206, 2565621, 2568796, 2568981, 2574030, 2574131, 2574141, 2578627, 2578663, 2578885, 2579065, 2579077, 2635958, 2649793, 2734444, 2861455, 2861629, 2861658, 2865379, 2870482, 2952278, 2952498, 2953380, 2953646, 2954045, 2961215, 2963399, 2965113, 3050013, 3054793, 3060562, 3064368, 3065540, 3071270, 3198531, 3202941, 3207186, 3212698, 3212831, 3216984, 3269161, 3269242, 3269345, 3269384, 3269648, 3272364, 3272373, 3272901, 3275844, 3275889, 3276055, 3276112, 3276338, 3281384, 3391440, 3446831, 3566844, 3567034, 3576305, 3576520, 3576930, 3577773, 3762045, 3831213, 3832600, 3833385, 3837227, 3854576, 3867523, 3882458, 3886721, 3894834, 3895218, 3968720, 3990998, 4015829, 4077594, 4077715, 4122689, 4129663, 4129881, 4139084, 4270110, 4270763, 4276070, 4276191, 4276232, 4278723, 4279961, 4367043, 4377497, 4381316, 4382546, 4384296, 4465032, 4467744, 4467939, 4468821, 4468838, 4469326, 4475002, 4537474, 4537577, 4537754, 4540724, 4541118, 4549018, 4549067, 4549408, 4549422, 4549492, 4549596, 4554195, 4554280, 4554574, 4554600, 4612672, 4615636, 4624751, 4624867, 4625247, 4625264, 4625281, 4627592, 4683911, 4687452, 4690406, 4695729, 4695874, 4696062, 4700630, 4766320, 4767973, 4768060, 4993225, 5159265, 5171735, 5189129, 5245046, 5247882, 5251325, 5256313, 5256698, 5256797, 5256893, 5324784, 5390902, 5390931, 5391085, 5391220, 5391236, 5391279, 5391282, 5391377, 5391380, 5391453, 5391473, 5394223, 5394323, 5394333, 5394339, 5397689, 5397898, 5397948, 5402627, 5403032, 5403154, 5403209, 5403227, 5403263, 5403265, 5464503, 5467911, 5467912, 5470826, 5471057, 5684600, 5696582, 5698334, 5699201, 5699552, 5700724, 5701298, 5781576, 5781697, 5783765, 5790648, 5792059, 5889731, 6025848, 6036027, 6036310, 6039777, 6041060, 6042154, 6042300, 6042748, 6181393, 6181615, 6181803, 6186102, 6446678, 6467331, 6683635, 6696415, 6710508, 6718912, 6721060, 6817577, 6845513, 6915249, 7205220, 7214255, 7446640, 7449110, 7588551, 7592640, 7592660, 7595985, 7825545, 8122476, 8125775, 8125899, 8129285, 8134250, 8134833, 8513600, 8513896, 8527107, 8527903, 8528744, 8529633, 8532949, 8610694, 8610714, 8610729, 8610801, 8612889, 8615245, 8620699, 8627976, 8627982, 8713012, 8718087, 8718806, 8718810, 8725538, 8781259, 8855771, 8856779, 8859358, 8866378, 8868892, 8871872, 8872713, 8874273, 9002408, 9011362, 9011410, 9105079, 9225193, 9227905, 9228456, 9231269, 9232851, 9234275, 9234727, 9234739, 9235957, 9240520, 9320003, 9320224, 9327139, 9331848, 9332017, 9332283, 9333459, 9333581, 9418089, 9420293, 9426381, 9426556, 9429880, 9564009, 9568628, 9572432, 9572850, 9575667, 9577761, 9577903, 9578559, 9578575, 9578685, 9578705, 9578737, 9579256, 9710327, 9723065, 9934535, 9942907, 9944866, 10039660, 10042544, 10043595, 10127574, 10133500, 10269804, 10275894, 10283495, 10284684, 10286428, 10429797, 10522200, 10649863, 10685532, 10686630, 10760758, 10772528, 10910095, 10958679, 10966805, 11004167, 11050180, 11051744, 11054147, 11103886, 11113978, 11127523, 11133250, 11138367, 11182099, 11202241, 11256355, 11348880, 11352816, 11352923, 11354504, 11356628, 11357445, 11357449, 11357800, 11359102, 11439374, 11439377, 11439816, 11441846, 11450646, 11453674, 11513005, 11524634, 11524818, 11537259, 11540294, 11546544, 11547866, 11552912, 11556338, 11558618, 11610097, 11610280, 11610416, 11610431, 11610590, 11610760, 11613402, 11626923, 11627204, 11627207, 11627218, 11690326, 11692470, 11697564, 11700402, 11701837, 11760273, 11763159, 11763188, 11763449, 11763493, 11763520, 11763826, 11768176, 11768524, 11768554, 11768726, 11768756, 11773570, 11839313, 11840534, 11842394, 11842610, 11844359, 12536885, 12540310, 12540698, 12540722, 12543613, 12543680, 12543883, 12544048, 12544100, 12549142, 12549146, 12758858, 12759087, 12763447, 12763572, 12765912, 12767316, 12767429, 12769168, 12772543, 12853939, 12853951, 12853985, 12854205, 12854842, 12858212, 12863148, 12863149, 12864945, 12866513, 12866832, 12954086, 12954131, 12955958, 12956698, 12956711, 12962889, 12966882, 12969102, 12969224, 13098305, 13098850, 13099030, 13099760, 13114762, 13114765, 13114964, 13115139, 13117959, 13249197, 13253482, 13254613, 13258406, 13259811, 13470723, 13472853, 13473269, 13473425, 13474643, 13480481, 13512700, 13512874, 13512875, 13516122, 13516137, 13524572, 13524590, 13524648, 13524750, 13526689, 13526812, 13527255, 13568612, 13572614, 13576065, 13578945, 13634413, 13634486, 13634616, 13634652, 13634674, 13634761, 13634819, 13634825, 13634830, 13634935, 13637817, 13637821, 13638010, 13638016, 13641214, 13641431, 13641451, 13641562, 13646164, 13646593, 13646608, 13646695, 13646709, 13649200, 13663979, 13668305, 13669706, 13673137, 13678629, 13708044, 13714547, 13714729, 13724639, 13732433, 13735373, 13738668, 13738697, 13744091, 13756779, 13759961, 13763318, 13768335, 13780724, 13780950, 13784030, 13814313, 13814531, 13814909, 13816212, 13819859, 13822245, 13822423, 13829496, 13829931, 13829937, 13832808, 13832815, 13832917, 13833128, 13833178, 13836224, 13836244, 13836456, 13836492, 13836543, 13836717, 13838936, 13841502, 13841627, 13841720, 13843755, 13870646, 13927058, 13927140, 13927168, 13927235, 13927239, 13927284, 13927313, 13927320, 13927353, 13927370, 13927487, 13927502, 13927545, 13930364, 13930365, 13930366, 13930481, 13930485, 13930487, 13930860, 13933786, 13933868, 13934048, 13934101, 13938832, 13939145, 13939181, 13939261, 13939269, 13939281, 13939377, 13941309, 13941534, 13943878, 13943921, 13943960, 13947497, 13956442, 13958662, 13962713, 13964127, 13965854, 14000520, 14003538, 14003540, 14003652, 14003656, 14003851, 14007049, 14007268, 14012427, 14172044, 14179419, 14186573, 14268501, 14272358, 14278977, 14279927, 14341990, 14345319, 14345464, 14348399, 14348452, 14348714, 14348718, 14348827, 14353883, 14353903, 14356020, 14356038, 14356328, 14358772, 14371171, 14371260, 14376217, 14380408, 14382294, 14512867, 14514873, 14520865, 14524658, 14525175, 14526688, 14528316, 14529211, 14529327, 14530955, 14530961, 14531903, 14585555, 14585561, 14585584, 14585736, 14585738, 14585744, 14586022, 14588876, 14589237, 14592232, 14592254, 14592289, 14592340, 14597688, 14602381, 14602656, 14602700, 14602854, 14602871, 14668158, 14669175, 14669726, 14714228, 14714707, 15162912, 15197223, 15197874, 15202115, 15202365, 15202501, 15202521, 15211440, 15256744, 15259269, 15293628, 15317341, 15333994, 15591072, 15594928, 15595277, 15595306, 15598480, 15602600, 15694515, 15697821, 15783207, 15786023, 15792194, 15794862, 15795449, 15795943, 15796544, 15798450, 15928581, 15933678, 15943000, 16298188, 16299992, 16308416, 16308767, 16308835, 16390796, 16390892, 16392980, 16397188, 16397503, 16463769, 16466829, 16466937, 16467307, 16470239, 16470497, 16470678, 16470729, 16475543, 16475746, 16490487, 16490786, 16490916, 16491521, 16644274, 16648788, 16648983, 16648992, 16649007, 16649024, 16651311, 16651369, 16651959, 16707894, 16710768, 16714605, 16714619, 16719493, 16719665, 17709189, 17805347, 17805382, 17906083, 18065934, 18066109, 18513456, 18527835]
 }
\ No newline at end of file
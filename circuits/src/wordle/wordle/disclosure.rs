@@ -0,0 +1,83 @@
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::Layouter,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+
+use super::WordState;
+
+// Layout for one row of the grid:
+//
+// row      column              selectors
+// 0        poly word           q_reveal
+// 1        reveal flag (public)
+// 2        revealed = flag * poly word (public)
+//
+// A hidden row shows up as 0 in the revealed words, as does a revealed row that wasn't played.
+#[derive(Debug, Clone)]
+pub(super) struct DisclosureConfig {
+    q_reveal: Selector,
+    column: Column<Advice>,
+    reveal_instance: Column<Instance>,
+    revealed_word_instance: Column<Instance>,
+}
+
+impl DisclosureConfig {
+    pub(super) fn configure<F: FieldExt>(
+        meta: &mut ConstraintSystem<F>,
+        column: Column<Advice>,
+        reveal_instance: Column<Instance>,
+        revealed_word_instance: Column<Instance>,
+    ) -> Self {
+        let q_reveal = meta.selector();
+
+        meta.enable_equality(column);
+        meta.enable_equality(reveal_instance);
+        meta.enable_equality(revealed_word_instance);
+
+        meta.create_gate("disclosure check", |meta| {
+            let q = meta.query_selector(q_reveal);
+            let word = meta.query_advice(column, Rotation::cur());
+            let flag = meta.query_advice(column, Rotation::next());
+            let revealed = meta.query_advice(column, Rotation(2));
+
+            vec![
+                q.clone() * flag.clone() * (Expression::Constant(F::one()) - flag.clone()),
+                q * (revealed - flag * word),
+            ]
+        });
+
+        Self {
+            q_reveal,
+            column,
+            reveal_instance,
+            revealed_word_instance,
+        }
+    }
+
+    // Reveals the poly word of every row whose public flag is set.
+    pub(super) fn assign<F: FieldExt>(
+        &self,
+        mut layouter: impl Layouter<F>,
+        words: &[WordState<F>],
+    ) -> Result<(), Error> {
+        for (idx, word) in words.iter().enumerate() {
+            let revealed = layouter.assign_region(
+                || format!("disclose word {}", idx),
+                |mut region| {
+                    self.q_reveal.enable(&mut region, 0)?;
+
+                    let poly_word = region.assign_advice(|| "poly word", self.column, 0, || word.poly_word.value().map(|p| p.evaluate()))?;
+                    region.constrain_equal(poly_word.cell(), word.poly_word.cell())?;
+                    let flag = region.assign_advice_from_instance(|| "reveal", self.reveal_instance, idx, self.column, 1)?;
+
+                    region.assign_advice(|| "revealed word", self.column, 2, || flag.value().copied() * poly_word.value().copied())
+                },
+            )?;
+            layouter.constrain_instance(revealed.cell(), self.revealed_word_instance, idx)?;
+        }
+
+        Ok(())
+    }
+}
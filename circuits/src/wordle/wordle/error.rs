@@ -0,0 +1,40 @@
+use std::fmt;
+
+/// What can go wrong handling games, proofs and their inputs outside of a circuit. Circuit
+/// synthesis itself still fails with `halo2_proofs::plonk::Error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordleError {
+    /// Not a word of lowercase letters of the right length.
+    InvalidWord(String),
+    /// A well formed word that isn't in the dictionary it's checked against.
+    UnknownWord(String),
+    /// A game the circuit can't prove, e.g. one that wasn't won.
+    InvalidGame(String),
+    /// Any other input that doesn't parse or is out of range.
+    InvalidInput(String),
+    /// A file couldn't be read or written.
+    Io(String),
+    MalformedParams(String),
+    MalformedProof(String),
+    /// keygen or proving failed, most likely because the witness doesn't satisfy the circuit.
+    Proving(String),
+    VerificationFailed(String),
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordleError::InvalidWord(word) => write!(f, "{:?} is not a valid word", word),
+            WordleError::UnknownWord(word) => write!(f, "{:?} is not in the dictionary", word),
+            WordleError::InvalidGame(reason) => write!(f, "invalid game: {}", reason),
+            WordleError::InvalidInput(reason) => write!(f, "invalid input: {}", reason),
+            WordleError::Io(reason) => write!(f, "i/o error: {}", reason),
+            WordleError::MalformedParams(reason) => write!(f, "malformed params: {}", reason),
+            WordleError::MalformedProof(reason) => write!(f, "malformed proof: {}", reason),
+            WordleError::Proving(reason) => write!(f, "proving failed: {}", reason),
+            WordleError::VerificationFailed(reason) => write!(f, "verification failed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for WordleError {}
@@ -0,0 +1,205 @@
+use halo2_gadgets::poseidon::{
+    primitives::{self as poseidon, ConstantLength, P128Pow5T3, Spec},
+    Hash as PoseidonHash, Pow5Chip, Pow5Config,
+};
+use halo2_proofs::{
+    arithmetic::FieldExt,
+    circuit::{AssignedCell, Layouter, Value},
+    pasta::Fp,
+    plonk::{Advice, Assigned, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+
+use super::WordleError;
+
+/// Depth of the dictionary tree, enough for 2^14 = 16384 words.
+pub const DICT_DEPTH: usize = 14;
+
+/// Siblings from the leaf up, and whether the running node is the right child at each level.
+#[derive(Debug, Clone, Copy)]
+pub struct MerklePath<F: FieldExt> {
+    pub siblings: [Value<F>; DICT_DEPTH],
+    pub positions: [Value<F>; DICT_DEPTH],
+}
+
+impl<F: FieldExt> Default for MerklePath<F> {
+    fn default() -> Self {
+        Self {
+            siblings: [Value::unknown(); DICT_DEPTH],
+            positions: [Value::unknown(); DICT_DEPTH],
+        }
+    }
+}
+
+// Layout for one level of the path:
+//
+// row      state[0]        state[1]        state[2]        selectors
+// 0        node            sibling         position        q_merkle
+// 1        left            right
+//
+// left and right are node and sibling, swapped when position is 1, and the next node is
+// Poseidon(left, right).
+#[derive(Debug, Clone)]
+pub(super) struct MerkleConfig<F: FieldExt> {
+    q_merkle: Selector,
+    state: [Column<Advice>; 3],
+    poseidon: Pow5Config<F, 3, 2>,
+    root_instance: Column<Instance>,
+}
+
+impl<F: FieldExt> MerkleConfig<F>
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    pub(super) fn configure(
+        meta: &mut ConstraintSystem<F>,
+        state: [Column<Advice>; 3],
+        poseidon: Pow5Config<F, 3, 2>,
+        root_instance: Column<Instance>,
+    ) -> Self {
+        let q_merkle = meta.selector();
+
+        for column in state {
+            meta.enable_equality(column);
+        }
+        meta.enable_equality(root_instance);
+
+        meta.create_gate("merkle swap check", |meta| {
+            let q = meta.query_selector(q_merkle);
+            let one = Expression::Constant(F::one());
+            let node = meta.query_advice(state[0], Rotation::cur());
+            let sibling = meta.query_advice(state[1], Rotation::cur());
+            let position = meta.query_advice(state[2], Rotation::cur());
+            let left = meta.query_advice(state[0], Rotation::next());
+            let right = meta.query_advice(state[1], Rotation::next());
+
+            vec![
+                q.clone() * position.clone() * (one - position.clone()),
+                q.clone() * (left - (node.clone() + position.clone() * (sibling.clone() - node.clone()))),
+                q * (right - (sibling.clone() + position * (node - sibling))),
+            ]
+        });
+
+        Self {
+            q_merkle,
+            state,
+            poseidon,
+            root_instance,
+        }
+    }
+
+    // Hashes `leaf` up along `path` and constrains the result to the public dictionary root.
+    pub(super) fn assign_path(
+        &self,
+        mut layouter: impl Layouter<F>,
+        leaf: &AssignedCell<Assigned<F>, F>,
+        path: &MerklePath<F>,
+    ) -> Result<(), Error> {
+        let mut node: Option<AssignedCell<F, F>> = None;
+        for level in 0..DICT_DEPTH {
+            let (left, right) = layouter.assign_region(
+                || format!("merkle level {}", level),
+                |mut region| {
+                    self.q_merkle.enable(&mut region, 0)?;
+
+                    let node_cell = match &node {
+                        Some(node) => {
+                            let cell = region.assign_advice(|| "node", self.state[0], 0, || node.value().copied())?;
+                            region.constrain_equal(cell.cell(), node.cell())?;
+                            cell
+                        }
+                        None => {
+                            let cell = region.assign_advice(|| "leaf", self.state[0], 0, || leaf.value().map(|v| v.evaluate()))?;
+                            region.constrain_equal(cell.cell(), leaf.cell())?;
+                            cell
+                        }
+                    };
+                    let sibling = path.siblings[level];
+                    let position = path.positions[level];
+                    region.assign_advice(|| "sibling", self.state[1], 0, || sibling)?;
+                    region.assign_advice(|| "position", self.state[2], 0, || position)?;
+
+                    let node = node_cell.value().copied();
+                    let left = node + position * (sibling - node);
+                    let right = sibling + position * (node - sibling);
+                    let left = region.assign_advice(|| "left", self.state[0], 1, || left)?;
+                    let right = region.assign_advice(|| "right", self.state[1], 1, || right)?;
+
+                    Ok((left, right))
+                },
+            )?;
+
+            let hasher = PoseidonHash::<_, _, P128Pow5T3, ConstantLength<2>, 3, 2>::init(
+                Pow5Chip::construct(self.poseidon.clone()),
+                layouter.namespace(|| format!("merkle hash {} init", level)),
+            )?;
+            node = Some(hasher.hash(layouter.namespace(|| format!("merkle hash {}", level)), [left, right])?);
+        }
+
+        layouter.constrain_instance(node.unwrap().cell(), self.root_instance, 0)
+    }
+}
+
+fn hash_pair(left: Fp, right: Fp) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([left, right])
+}
+
+/// Poseidon Merkle tree over a word list's polynomial hashes. Unused leaves are 0, which is
+/// also what the unused guess rows prove membership of.
+#[derive(Debug, Clone)]
+pub struct DictionaryTree {
+    layers: Vec<Vec<Fp>>,
+}
+
+impl DictionaryTree {
+    pub fn new(words: &[u64]) -> Result<Self, WordleError> {
+        if words.len() >= 1 << DICT_DEPTH {
+            return Err(WordleError::InvalidInput(format!("{} words don't fit in the dictionary tree", words.len())));
+        }
+
+        let mut leaves = vec![Fp::zero(); 1 << DICT_DEPTH];
+        for (i, word) in words.iter().enumerate() {
+            leaves[i] = Fp::from(*word);
+        }
+
+        // most of the tree is empty, so only hash an empty pair once per level
+        let mut empty = Fp::zero();
+        let mut layers = vec![leaves];
+        for _ in 0..DICT_DEPTH {
+            let empty_parent = hash_pair(empty, empty);
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| {
+                    if pair[0] == empty && pair[1] == empty {
+                        empty_parent
+                    } else {
+                        hash_pair(pair[0], pair[1])
+                    }
+                })
+                .collect();
+            layers.push(next);
+            empty = empty_parent;
+        }
+
+        Ok(Self { layers })
+    }
+
+    pub fn root(&self) -> Fp {
+        self.layers[DICT_DEPTH][0]
+    }
+
+    pub fn path(&self, word: u64) -> Option<MerklePath<Fp>> {
+        let mut index = self.layers[0].iter().position(|leaf| *leaf == Fp::from(word))?;
+
+        let mut path = MerklePath::default();
+        for level in 0..DICT_DEPTH {
+            path.siblings[level] = Value::known(self.layers[level][index ^ 1]);
+            path.positions[level] = Value::known(Fp::from((index & 1) as u64));
+            index >>= 1;
+        }
+
+        Some(path)
+    }
+}
@@ -0,0 +1,130 @@
+use halo2_proofs::{
+    circuit::Value,
+    pasta::Fp,
+    plonk::Assigned,
+};
+
+use super::utils::*;
+use super::{Dictionary, Grid, PublicInputs, Word, WordleCircuit, WordleError};
+
+/// A finished game as the player saw it, and everything needed to prove it: the
+/// `WordleCircuit` witness and the `PublicInputs` it's checked against. `dictionary` is the
+/// one the circuit is built with.
+///
+/// `Play::new` checks that the game is one the circuit can prove. A `Play` built by hand
+/// skips that, which is only useful for making proofs that shouldn't verify.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Play<const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub words: Vec<Word<LEN>>,
+    pub final_word: Word<LEN>,
+    pub salt: Fp,
+    pub day: u64,
+    pub hard_mode: bool,
+    pub player_id: Fp,
+    pub player_secret: Fp,
+    pub dictionary: Dictionary,
+}
+
+impl<const LEN: usize, const GUESSES: usize> Play<LEN, GUESSES> {
+    pub fn new(
+        words: Vec<Word<LEN>>,
+        final_word: Word<LEN>,
+        salt: Fp,
+        day: u64,
+        hard_mode: bool,
+        player_id: Fp,
+        player_secret: Fp,
+        dictionary: Dictionary,
+    ) -> Result<Self, WordleError> {
+        if let Some(word) = words.iter().find(|word| !dictionary.is_guess(word)) {
+            return Err(WordleError::UnknownWord(word.to_string()));
+        }
+        if !dictionary.is_answer(&final_word) {
+            return Err(WordleError::UnknownWord(final_word.to_string()));
+        }
+        if words.len() > GUESSES {
+            return Err(WordleError::InvalidGame(format!("{} guesses played, at most {} fit", words.len(), GUESSES)));
+        }
+        // the game ends with the solve, so only the last guess may be the solution
+        if words.last() != Some(&final_word) || words[..words.len() - 1].contains(&final_word) {
+            return Err(WordleError::InvalidGame(String::from("the game has to end with the first solve")));
+        }
+        if hard_mode {
+            for pair in words.windows(2) {
+                if !satisfies_hard_mode(&pair[0], &compute_diff(&pair[0], &final_word), &pair[1]) {
+                    return Err(WordleError::InvalidGame(format!("\"{}\" doesn't use the hints revealed by \"{}\"", pair[1], pair[0])));
+                }
+            }
+        }
+
+        Ok(Self {
+            words,
+            final_word,
+            salt,
+            day,
+            hard_mode,
+            player_id,
+            player_secret,
+            dictionary,
+        })
+    }
+
+    // The tiles of every guess played.
+    pub fn grid(&self) -> Grid {
+        Grid {
+            rows: self
+                .words
+                .iter()
+                .map(|word| compute_diff(word, &self.final_word))
+                .collect(),
+        }
+    }
+
+    // Rows past the played words are left inactive, i.e. all zeros.
+    pub fn circuit(&self) -> WordleCircuit<Fp, LEN, GUESSES> {
+        let mut poly_words: [Value<Assigned<Fp>>; GUESSES] = [Value::known(Fp::zero().into()); GUESSES];
+        let mut word_chars: [[Value<Assigned<Fp>>; LEN]; GUESSES] = [[Value::known(Fp::zero().into()); LEN]; GUESSES];
+
+        for (idx, word) in self.words.iter().enumerate().take(GUESSES) {
+            let (poly_word, chars) = word_witness(word);
+            poly_words[idx] = poly_word;
+            word_chars[idx] = chars;
+        }
+
+        let (final_poly_word, final_chars) = word_witness(&self.final_word);
+
+        WordleCircuit {
+            poly_words,
+            word_chars,
+            final_poly_word,
+            final_chars,
+            salt: Value::known(self.salt),
+            player_id: Value::known(self.player_id),
+            player_secret: Value::known(self.player_secret),
+            dictionary: self.dictionary.clone(),
+        }
+    }
+
+    pub fn public_inputs(&self) -> PublicInputs<LEN, GUESSES> {
+        PublicInputs {
+            commitment: compute_commitment(&self.final_word, self.salt, self.day),
+            day: self.day,
+            hard_mode: self.hard_mode,
+            grid: self.grid(),
+            player_tag: compute_player_tag(self.player_id),
+            registration: compute_registration(self.player_id, self.player_secret),
+            nullifier: compute_nullifier(self.player_secret, self.day),
+        }
+    }
+}
+
+// The poly word and letter codes of `word`, as the circuits take them.
+pub(super) fn word_witness<const LEN: usize>(word: &Word<LEN>) -> (Value<Assigned<Fp>>, [Value<Assigned<Fp>>; LEN]) {
+    let chars = word.chars();
+    let mut word_chars: [Value<Assigned<Fp>>; LEN] = [Value::known(Fp::zero().into()); LEN];
+    for i in 0..LEN {
+        word_chars[i] = Value::known(Fp::from(chars[i]).into());
+    }
+
+    (Value::known(Fp::from(word.poly_word()).into()), word_chars)
+}
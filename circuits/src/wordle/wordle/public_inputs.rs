@@ -0,0 +1,442 @@
+use halo2_proofs::pasta::{group::ff::PrimeField, Fp};
+use serde::{Deserialize, Serialize};
+
+use super::utils::*;
+use super::{Grid, Tile, Word, WordleError};
+
+fn fp_to_u64(value: Fp) -> Option<u64> {
+    let repr = value.to_repr();
+    if repr[8..].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(u64::from_le_bytes(repr[..8].try_into().unwrap()))
+}
+
+fn fp_to_bool(value: Fp) -> Option<bool> {
+    match fp_to_u64(value)? {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+// The value of a column that holds exactly one.
+fn single(column: &[Fp]) -> Option<Fp> {
+    match column {
+        [value] => Some(*value),
+        _ => None,
+    }
+}
+
+/// The public inputs of a `WordleCircuit` proof. Provers and verifiers should go through
+/// `to_instance_columns` rather than laying out the columns themselves, and exchange this
+/// type rather than loose values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicInputs<const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    #[serde(with = "fp_repr")]
+    pub commitment: Fp,
+    pub day: u64,
+    pub hard_mode: bool,
+    pub grid: Grid,
+    #[serde(with = "fp_repr")]
+    pub player_tag: Fp,
+    #[serde(with = "fp_repr")]
+    pub registration: Fp,
+    #[serde(with = "fp_repr")]
+    pub nullifier: Fp,
+}
+
+impl<const LEN: usize, const GUESSES: usize> PublicInputs<LEN, GUESSES> {
+    pub fn guess_count(&self) -> usize {
+        self.grid.guess_count()
+    }
+
+    // One vector per instance column, in the order `WordleCircuit` allocates them. Rows past
+    // the guess count are GRAY. Fails on a grid that doesn't fit the circuit, which can only
+    // come from deserializing or building one by hand.
+    pub fn to_instance_columns(&self) -> Result<Vec<Vec<Fp>>, WordleError> {
+        Ok(vec![
+            vec![self.commitment],
+            vec![Fp::from(self.day)],
+            vec![Fp::from(self.guess_count() as u64)],
+            vec![Fp::from(self.hard_mode as u64)],
+            self.grid.to_colors::<LEN, GUESSES>()?,
+            vec![self.player_tag],
+            vec![self.registration],
+            vec![self.nullifier],
+        ])
+    }
+
+    // The inverse of `to_instance_columns`. Fails if the columns aren't laid out the way it
+    // would have, e.g. a color set past the guess count.
+    pub fn from_instance_columns(columns: &[Vec<Fp>]) -> Result<Self, WordleError> {
+        Self::parse_instance_columns(columns)
+            .ok_or_else(|| WordleError::InvalidInput(String::from("not the instance columns of a WordleCircuit")))
+    }
+
+    fn parse_instance_columns(columns: &[Vec<Fp>]) -> Option<Self> {
+        let (commitment, day, guess_count, hard_mode, colors, player_tag, registration, nullifier) = match columns {
+            [commitment, day, guess_count, hard_mode, colors, player_tag, registration, nullifier] => {
+                (commitment, day, guess_count, hard_mode, colors, player_tag, registration, nullifier)
+            }
+            _ => return None,
+        };
+        let guess_count = fp_to_u64(single(guess_count)?)? as usize;
+
+        Some(Self {
+            commitment: single(commitment)?,
+            day: fp_to_u64(single(day)?)?,
+            hard_mode: fp_to_bool(single(hard_mode)?)?,
+            grid: Grid::from_colors::<LEN, GUESSES>(colors, guess_count).ok()?,
+            player_tag: single(player_tag)?,
+            registration: single(registration)?,
+            nullifier: single(nullifier)?,
+        })
+    }
+}
+
+/// The public inputs of a `MerkleWordleCircuit` proof: those of the game, and the root of
+/// the dictionary tree the guesses are opened against, which goes in a column of its own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerklePublicInputs<const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub game: PublicInputs<LEN, GUESSES>,
+    #[serde(with = "fp_repr")]
+    pub dictionary_root: Fp,
+}
+
+impl<const LEN: usize, const GUESSES: usize> MerklePublicInputs<LEN, GUESSES> {
+    pub fn to_instance_columns(&self) -> Result<Vec<Vec<Fp>>, WordleError> {
+        let mut columns = self.game.to_instance_columns()?;
+        columns.push(vec![self.dictionary_root]);
+        Ok(columns)
+    }
+
+    pub fn from_instance_columns(columns: &[Vec<Fp>]) -> Result<Self, WordleError> {
+        Self::parse_instance_columns(columns)
+            .ok_or_else(|| WordleError::InvalidInput(String::from("not the instance columns of a MerkleWordleCircuit")))
+    }
+
+    fn parse_instance_columns(columns: &[Vec<Fp>]) -> Option<Self> {
+        let (dictionary_root, game) = columns.split_last()?;
+        Some(Self {
+            game: PublicInputs::parse_instance_columns(game)?,
+            dictionary_root: single(dictionary_root)?,
+        })
+    }
+}
+
+/// The public inputs of a `PrivateGridWordleCircuit` proof: those of the game without the
+/// grid, of which only the guess count is public.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrivateGridPublicInputs<const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    #[serde(with = "fp_repr")]
+    pub commitment: Fp,
+    pub day: u64,
+    pub guess_count: usize,
+    pub hard_mode: bool,
+    #[serde(with = "fp_repr")]
+    pub player_tag: Fp,
+    #[serde(with = "fp_repr")]
+    pub registration: Fp,
+    #[serde(with = "fp_repr")]
+    pub nullifier: Fp,
+}
+
+impl<const LEN: usize, const GUESSES: usize> PrivateGridPublicInputs<LEN, GUESSES> {
+    // `WordleCircuit`'s columns without the colors.
+    pub fn to_instance_columns(&self) -> Result<Vec<Vec<Fp>>, WordleError> {
+        if self.guess_count > GUESSES {
+            return Err(WordleError::InvalidInput(format!("{} guesses in a grid of {}", self.guess_count, GUESSES)));
+        }
+
+        Ok(vec![
+            vec![self.commitment],
+            vec![Fp::from(self.day)],
+            vec![Fp::from(self.guess_count as u64)],
+            vec![Fp::from(self.hard_mode as u64)],
+            vec![self.player_tag],
+            vec![self.registration],
+            vec![self.nullifier],
+        ])
+    }
+
+    pub fn from_instance_columns(columns: &[Vec<Fp>]) -> Result<Self, WordleError> {
+        Self::parse_instance_columns(columns)
+            .ok_or_else(|| WordleError::InvalidInput(String::from("not the instance columns of a PrivateGridWordleCircuit")))
+    }
+
+    fn parse_instance_columns(columns: &[Vec<Fp>]) -> Option<Self> {
+        let (commitment, day, guess_count, hard_mode, player_tag, registration, nullifier) = match columns {
+            [commitment, day, guess_count, hard_mode, player_tag, registration, nullifier] => {
+                (commitment, day, guess_count, hard_mode, player_tag, registration, nullifier)
+            }
+            _ => return None,
+        };
+
+        let guess_count = fp_to_u64(single(guess_count)?)? as usize;
+        if guess_count > GUESSES {
+            return None;
+        }
+
+        Some(Self {
+            commitment: single(commitment)?,
+            day: fp_to_u64(single(day)?)?,
+            guess_count,
+            hard_mode: fp_to_bool(single(hard_mode)?)?,
+            player_tag: single(player_tag)?,
+            registration: single(registration)?,
+            nullifier: single(nullifier)?,
+        })
+    }
+}
+
+// What's left public once the grid is hidden.
+impl<const LEN: usize, const GUESSES: usize> From<PublicInputs<LEN, GUESSES>> for PrivateGridPublicInputs<LEN, GUESSES> {
+    fn from(public_inputs: PublicInputs<LEN, GUESSES>) -> Self {
+        Self {
+            commitment: public_inputs.commitment,
+            day: public_inputs.day,
+            guess_count: public_inputs.guess_count(),
+            hard_mode: public_inputs.hard_mode,
+            player_tag: public_inputs.player_tag,
+            registration: public_inputs.registration,
+            nullifier: public_inputs.nullifier,
+        }
+    }
+}
+
+/// The public inputs of a `DisclosingWordleCircuit` proof: those of the game, which rows
+/// are revealed, and the word of each revealed row that was played.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisclosingPublicInputs<const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub game: PublicInputs<LEN, GUESSES>,
+    pub reveal: Vec<bool>,
+    pub revealed: Vec<Option<Word<LEN>>>,
+}
+
+impl<const LEN: usize, const GUESSES: usize> DisclosingPublicInputs<LEN, GUESSES> {
+    // The player's side, which knows the words. `reveal` has a flag per row of the grid.
+    pub fn new(game: PublicInputs<LEN, GUESSES>, words: &[Word<LEN>], reveal: &[bool]) -> Self {
+        Self {
+            game,
+            reveal: reveal.to_vec(),
+            revealed: reveal
+                .iter()
+                .enumerate()
+                .map(|(idx, flag)| words.get(idx).filter(|_| *flag).cloned())
+                .collect(),
+        }
+    }
+
+    // `WordleCircuit`'s columns, then the flags and the revealed poly words, 0 for the rows
+    // that aren't revealed or weren't played.
+    pub fn to_instance_columns(&self) -> Result<Vec<Vec<Fp>>, WordleError> {
+        if self.reveal.len() != GUESSES || self.revealed.len() != GUESSES {
+            return Err(WordleError::InvalidInput(format!("{} flags and {} words for a grid of {}", self.reveal.len(), self.revealed.len(), GUESSES)));
+        }
+        if self.reveal.iter().zip(&self.revealed).any(|(flag, word)| !*flag && word.is_some()) {
+            return Err(WordleError::InvalidInput(String::from("a word given for a hidden row")));
+        }
+
+        let mut columns = self.game.to_instance_columns()?;
+        columns.push(self.reveal.iter().map(|flag| Fp::from(*flag as u64)).collect());
+        columns.push(
+            self.revealed
+                .iter()
+                .map(|word| word.as_ref().map_or(Fp::zero(), |word| Fp::from(word.poly_word())))
+                .collect(),
+        );
+        Ok(columns)
+    }
+
+    pub fn from_instance_columns(columns: &[Vec<Fp>]) -> Result<Self, WordleError> {
+        Self::parse_instance_columns(columns)
+            .ok_or_else(|| WordleError::InvalidInput(String::from("not the instance columns of a DisclosingWordleCircuit")))
+    }
+
+    fn parse_instance_columns(columns: &[Vec<Fp>]) -> Option<Self> {
+        let (game, flags, revealed) = match columns {
+            [game @ .., flags, revealed] if flags.len() == GUESSES && revealed.len() == GUESSES => (game, flags, revealed),
+            _ => return None,
+        };
+
+        let reveal = flags.iter().map(|flag| fp_to_bool(*flag)).collect::<Option<Vec<_>>>()?;
+        let revealed = reveal
+            .iter()
+            .zip(revealed)
+            .map(|(flag, poly_word)| match fp_to_u64(*poly_word)? {
+                0 => Some(None),
+                _ if !*flag => None,
+                poly_word => Word::new(&polyhash_to_word(poly_word).ok()?).ok().map(Some),
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            game: PublicInputs::parse_instance_columns(game)?,
+            reveal,
+            revealed,
+        })
+    }
+}
+
+/// The public inputs of a `MultiWordleCircuit` proof: a commitment and a grid per board,
+/// first board first, and the rest as for `WordleCircuit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiPublicInputs<const BOARDS: usize, const LEN: usize = WORD_LEN, const GUESSES: usize = WORD_COUNT> {
+    pub commitments: Vec<FpRepr>,
+    pub day: u64,
+    pub hard_mode: bool,
+    pub grids: Vec<Grid>,
+    #[serde(with = "fp_repr")]
+    pub player_tag: Fp,
+    #[serde(with = "fp_repr")]
+    pub registration: Fp,
+    #[serde(with = "fp_repr")]
+    pub nullifier: Fp,
+}
+
+impl<const BOARDS: usize, const LEN: usize, const GUESSES: usize> MultiPublicInputs<BOARDS, LEN, GUESSES> {
+    // Merges the public inputs of each board played on its own, which have to agree on
+    // everything but the commitment and the grid.
+    pub fn new(boards: &[PublicInputs<LEN, GUESSES>]) -> Result<Self, WordleError> {
+        let first = match boards {
+            [first, ..] if boards.len() == BOARDS => first,
+            _ => return Err(WordleError::InvalidInput(format!("{} boards in a game of {}", boards.len(), BOARDS))),
+        };
+        let same_game = |board: &PublicInputs<LEN, GUESSES>| {
+            (board.day, board.hard_mode, board.player_tag, board.registration, board.nullifier)
+                == (first.day, first.hard_mode, first.player_tag, first.registration, first.nullifier)
+        };
+        if !boards.iter().all(same_game) {
+            return Err(WordleError::InvalidInput(String::from("boards from different games")));
+        }
+
+        Ok(Self {
+            commitments: boards.iter().map(|board| FpRepr(board.commitment)).collect(),
+            day: first.day,
+            hard_mode: first.hard_mode,
+            grids: boards.iter().map(|board| board.grid.clone()).collect(),
+            player_tag: first.player_tag,
+            registration: first.registration,
+            nullifier: first.nullifier,
+        })
+    }
+
+    // `WordleCircuit`'s columns, with a commitment and a guess count per board, and the
+    // colors of every board one after another.
+    pub fn to_instance_columns(&self) -> Result<Vec<Vec<Fp>>, WordleError> {
+        if self.commitments.len() != BOARDS || self.grids.len() != BOARDS {
+            return Err(WordleError::InvalidInput(format!("{} commitments and {} grids for {} boards", self.commitments.len(), self.grids.len(), BOARDS)));
+        }
+
+        let mut colors = vec![];
+        for grid in self.grids.iter() {
+            colors.extend(grid.to_colors::<LEN, GUESSES>()?);
+        }
+        Ok(vec![
+            self.commitments.iter().map(|commitment| commitment.0).collect(),
+            vec![Fp::from(self.day)],
+            self.grids.iter().map(|grid| Fp::from(grid.guess_count() as u64)).collect(),
+            vec![Fp::from(self.hard_mode as u64)],
+            colors,
+            vec![self.player_tag],
+            vec![self.registration],
+            vec![self.nullifier],
+        ])
+    }
+
+    pub fn from_instance_columns(columns: &[Vec<Fp>]) -> Result<Self, WordleError> {
+        Self::parse_instance_columns(columns)
+            .ok_or_else(|| WordleError::InvalidInput(String::from("not the instance columns of a MultiWordleCircuit")))
+    }
+
+    fn parse_instance_columns(columns: &[Vec<Fp>]) -> Option<Self> {
+        let (commitments, day, guess_counts, hard_mode, colors, player_tag, registration, nullifier) = match columns {
+            [commitments, day, guess_counts, hard_mode, colors, player_tag, registration, nullifier]
+                if commitments.len() == BOARDS && guess_counts.len() == BOARDS && colors.len() == BOARDS * GUESSES * LEN =>
+            {
+                (commitments, day, guess_counts, hard_mode, colors, player_tag, registration, nullifier)
+            }
+            _ => return None,
+        };
+
+        let grids = guess_counts
+            .iter()
+            .zip(colors.chunks(GUESSES * LEN))
+            .map(|(guess_count, colors)| Grid::from_colors::<LEN, GUESSES>(colors, fp_to_u64(*guess_count)? as usize).ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            commitments: commitments.iter().map(|commitment| FpRepr(*commitment)).collect(),
+            day: fp_to_u64(single(day)?)?,
+            hard_mode: fp_to_bool(single(hard_mode)?)?,
+            grids,
+            player_tag: single(player_tag)?,
+            registration: single(registration)?,
+            nullifier: single(nullifier)?,
+        })
+    }
+}
+
+/// The public inputs of a `FeedbackCircuit` proof: the tiles the host answered `guess` with
+/// on `day`, and the commitment to that day's solution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedbackPublicInputs<const LEN: usize = WORD_LEN> {
+    #[serde(with = "fp_repr")]
+    pub commitment: Fp,
+    pub day: u64,
+    pub guess: Word<LEN>,
+    pub tiles: Vec<Tile>,
+}
+
+impl<const LEN: usize> FeedbackPublicInputs<LEN> {
+    // The host's side, which knows the solution.
+    pub fn new(guess: Word<LEN>, final_word: &Word<LEN>, salt: Fp, day: u64) -> Self {
+        Self {
+            commitment: compute_commitment(final_word, salt, day),
+            day,
+            tiles: compute_diff(&guess, final_word),
+            guess,
+        }
+    }
+
+    // `WordleCircuit`'s columns with a single row of colors, the player's columns empty, and
+    // the guess's poly word last.
+    pub fn to_instance_columns(&self) -> Result<Vec<Vec<Fp>>, WordleError> {
+        Ok(vec![
+            vec![self.commitment],
+            vec![Fp::from(self.day)],
+            vec![],
+            vec![],
+            Grid::from(vec![self.tiles.clone()]).to_colors::<LEN, 1>()?,
+            vec![],
+            vec![],
+            vec![],
+            vec![Fp::from(self.guess.poly_word())],
+        ])
+    }
+
+    pub fn from_instance_columns(columns: &[Vec<Fp>]) -> Result<Self, WordleError> {
+        Self::parse_instance_columns(columns)
+            .ok_or_else(|| WordleError::InvalidInput(String::from("not the instance columns of a FeedbackCircuit")))
+    }
+
+    fn parse_instance_columns(columns: &[Vec<Fp>]) -> Option<Self> {
+        let (commitment, day, colors, guess) = match columns {
+            [commitment, day, guess_count, hard_mode, colors, player_tag, registration, nullifier, guess]
+                if [guess_count, hard_mode, player_tag, registration, nullifier].iter().all(|column| column.is_empty()) =>
+            {
+                (commitment, day, colors, guess)
+            }
+            _ => return None,
+        };
+
+        let guess = polyhash_to_word(fp_to_u64(single(guess)?)?).ok()?;
+        Some(Self {
+            commitment: single(commitment)?,
+            day: fp_to_u64(single(day)?)?,
+            guess: Word::new(&guess).ok()?,
+            tiles: Grid::from_colors::<LEN, 1>(colors, 1).ok()?.rows.pop()?,
+        })
+    }
+}
@@ -0,0 +1,221 @@
+use halo2_gadgets::ecc::{
+    chip::{BaseFieldElem, EccChip, EccConfig, FixedPoint, FullScalar, ShortScalar, H},
+    FixedPoints, NonIdentityPoint, ScalarVar,
+};
+use halo2_gadgets::poseidon::{
+    primitives::{self as poseidon, ConstantLength, P128Pow5T3},
+    Pow5Config,
+};
+use halo2_gadgets::sinsemilla::primitives::K;
+use halo2_gadgets::utilities::lookup_range_check::LookupRangeCheckConfig;
+use halo2_proofs::{
+    arithmetic::CurveAffine,
+    circuit::{AssignedCell, Layouter, Value},
+    pasta::{
+        group::{ff::{Field, PrimeField}, prime::PrimeCurveAffine, Curve, Group},
+        pallas, Fp, Fq,
+    },
+    plonk::{Advice, Column, ConstraintSystem, Error, Fixed},
+};
+use rand_core::RngCore;
+use std::fmt;
+
+use super::poseidon_hash;
+use super::table::DictTableConfig;
+
+// The signature check only multiplies variable bases, so the ECC chip gets no fixed bases.
+// Empty enums can't be constructed, which makes that explicit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoFixedBases {}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoFullScalarBase {}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoShortScalarBase {}
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoBaseFieldBase {}
+
+impl FixedPoints<pallas::Affine> for NoFixedBases {
+    type FullScalar = NoFullScalarBase;
+    type ShortScalar = NoShortScalarBase;
+    type Base = NoBaseFieldBase;
+}
+
+macro_rules! no_fixed_point {
+    ($base:ty, $kind:ty) => {
+        impl FixedPoint<pallas::Affine> for $base {
+            type FixedScalarKind = $kind;
+
+            fn generator(&self) -> pallas::Affine {
+                match *self {}
+            }
+
+            fn u(&self) -> Vec<[[u8; 32]; H]> {
+                match *self {}
+            }
+
+            fn z(&self) -> Vec<u64> {
+                match *self {}
+            }
+        }
+    };
+}
+
+no_fixed_point!(NoFullScalarBase, FullScalar);
+no_fixed_point!(NoShortScalarBase, ShortScalar);
+no_fixed_point!(NoBaseFieldBase, BaseFieldElem);
+
+/// A Schnorr signature over Pallas. `s` is kept below the base field modulus so the circuit
+/// can witness it as a base field element.
+#[derive(Debug, Clone, Copy)]
+pub struct Signature {
+    pub r: pallas::Affine,
+    pub s: Fp,
+}
+
+/// A player's signing key. Its `key_id` is the player id proofs are bound to.
+#[derive(Clone)]
+pub struct PlayerKey {
+    secret: Fq,
+}
+
+// Shows the public key only, so the secret can't end up in logs.
+impl fmt::Debug for PlayerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PlayerKey")
+            .field("public_key", &self.public_key())
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl PlayerKey {
+    pub fn random(mut rng: impl RngCore) -> Self {
+        Self {
+            secret: Fq::random(&mut rng),
+        }
+    }
+
+    pub fn public_key(&self) -> pallas::Affine {
+        (pallas::Point::generator() * self.secret).to_affine()
+    }
+
+    pub fn sign(&self, message: Fp, mut rng: impl RngCore) -> Signature {
+        let public_key = self.public_key();
+        loop {
+            let nonce = Fq::random(&mut rng);
+            let r = (pallas::Point::generator() * nonce).to_affine();
+            let c = Fq::from_repr(compute_challenge(r, public_key, message).to_repr()).unwrap();
+            let s = nonce + c * self.secret;
+            if let Some(s) = Option::from(Fp::from_repr(s.to_repr())) {
+                return Signature { r, s };
+            }
+        }
+    }
+}
+
+fn coordinates(point: pallas::Affine) -> (Fp, Fp) {
+    let coordinates = point.coordinates().unwrap();
+    (*coordinates.x(), *coordinates.y())
+}
+
+// c = Poseidon(R.x, pk.x, message)
+fn compute_challenge(r: pallas::Affine, public_key: pallas::Affine, message: Fp) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<3>, 3, 2>::init()
+        .hash([coordinates(r).0, coordinates(public_key).0, message])
+}
+
+// The player id of a signing key, its x coordinate, which pins the key up to its negation,
+// whose secret only the same key holder has. See `compute_player_tag`.
+pub fn key_id(public_key: pallas::Affine) -> Fp {
+    coordinates(public_key).0
+}
+
+// Checks [s]G == R + [c]pk with c = Poseidon(R.x, pk.x, message), using variable-base
+// multiplication for both sides; the generator is pinned to its coordinates as constants.
+#[derive(Debug, Clone)]
+pub(super) struct SignatureConfig {
+    ecc: EccConfig<NoFixedBases>,
+    range_table: DictTableConfig<Fp>,
+    poseidon: Pow5Config<Fp, 3, 2>,
+    s_column: Column<Advice>,
+}
+
+impl SignatureConfig {
+    pub(super) fn configure(
+        meta: &mut ConstraintSystem<Fp>,
+        advices: [Column<Advice>; 10],
+        lagrange_coeffs: [Column<Fixed>; 8],
+        poseidon: Pow5Config<Fp, 3, 2>,
+    ) -> Self {
+        meta.enable_constant(lagrange_coeffs[0]);
+
+        let range_table = DictTableConfig::configure(meta);
+        let range_check = LookupRangeCheckConfig::configure(meta, advices[9], range_table.value);
+        let ecc = EccChip::<NoFixedBases>::configure(meta, advices, lagrange_coeffs, range_check);
+
+        Self {
+            ecc,
+            range_table,
+            poseidon,
+            s_column: advices[0],
+        }
+    }
+
+    pub(super) fn load(&self, layouter: &mut impl Layouter<Fp>) -> Result<(), Error> {
+        self.range_table.load(layouter, &(0..1u64 << K).collect::<Vec<_>>())
+    }
+
+    pub(super) fn hash<const L: usize>(
+        &self,
+        layouter: impl Layouter<Fp>,
+        message: [AssignedCell<Fp, Fp>; L],
+    ) -> Result<AssignedCell<Fp, Fp>, Error> {
+        poseidon_hash(self.poseidon.clone(), self.s_column, layouter, message)
+    }
+
+    // Verifies the signature over `message` and returns the signer's id, see `key_id`.
+    pub(super) fn verify(
+        &self,
+        mut layouter: impl Layouter<Fp>,
+        public_key: Value<pallas::Affine>,
+        signature: Value<Signature>,
+        message: AssignedCell<Fp, Fp>,
+    ) -> Result<AssignedCell<Fp, Fp>, Error> {
+        let chip = EccChip::construct(self.ecc.clone());
+
+        let public_key = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "public key"), public_key)?;
+        let r = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "R"), signature.map(|signature| signature.r))?;
+
+        let (gx, gy) = coordinates(pallas::Affine::generator());
+        let generator = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "generator"), Value::known(pallas::Affine::generator()))?;
+        layouter.assign_region(
+            || "fix generator",
+            |mut region| {
+                let x = generator.inner().x().copy_advice(|| "generator x", &mut region, self.s_column, 0)?;
+                region.constrain_constant(x.cell(), gx)?;
+                let y = generator.inner().y().copy_advice(|| "generator y", &mut region, self.s_column, 1)?;
+                region.constrain_constant(y.cell(), gy)
+            },
+        )?;
+
+        let c = self.hash(
+            layouter.namespace(|| "challenge"),
+            [r.inner().x(), public_key.inner().x(), message],
+        )?;
+
+        let s = layouter.assign_region(
+            || "signature scalar",
+            |mut region| region.assign_advice(|| "s", self.s_column, 0, || signature.map(|signature| signature.s)),
+        )?;
+
+        let c = ScalarVar::from_base(chip.clone(), layouter.namespace(|| "c"), &c)?;
+        let s = ScalarVar::from_base(chip, layouter.namespace(|| "s"), &s)?;
+
+        let (lhs, _) = generator.mul(layouter.namespace(|| "[s]G"), s)?;
+        let (c_public_key, _) = public_key.mul(layouter.namespace(|| "[c]pk"), c)?;
+        let rhs = r.add(layouter.namespace(|| "R + [c]pk"), &c_public_key)?;
+        lhs.constrain_equal(layouter.namespace(|| "[s]G == R + [c]pk"), &rhs)?;
+
+        Ok(public_key.inner().x())
+    }
+}
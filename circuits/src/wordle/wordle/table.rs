@@ -8,19 +8,66 @@ use halo2_proofs::{
 
 use serde::{Deserialize, Serialize};
 
-use std::error::Error as StdError;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 
 use super::utils::*;
 use super::dict::*;
+use super::{Word, WordleError};
 
 #[derive(Serialize, Deserialize)]
 struct Dict {
     words: Vec<String>,
 }
 
+fn read_dict<P: AsRef<Path>>(path: P) -> Result<Vec<u64>, WordleError> {
+    let path = path.as_ref();
+    let invalid = |err: &dyn std::fmt::Display| WordleError::InvalidInput(format!("{}: {}", path.display(), err));
+    let file = File::open(path).map_err(|err| WordleError::Io(format!("{}: {}", path.display(), err)))?;
+    let dict: Dict = serde_json::from_reader(BufReader::new(file)).map_err(|err| invalid(&err))?;
+    dict.words.iter().map(|word| word_to_polyhash(word)).collect()
+}
+
+/// The word lists the circuit checks against, as polynomial hashes: any valid guess for
+/// the guesses, and the curated list the host picks solutions from for the answers.
+///
+/// Both lists are baked into the proving and verifying keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dictionary {
+    pub guesses: Vec<u64>,
+    pub answers: Vec<u64>,
+}
+
+impl Dictionary {
+    /// Reads both lists from JSON files of the form `{"words": [...]}`, like `dict.json` and `answers.json`.
+    pub fn from_json<P: AsRef<Path>>(guesses: P, answers: P) -> Result<Self, WordleError> {
+        Ok(Self {
+            guesses: read_dict(guesses)?,
+            answers: read_dict(answers)?,
+        })
+    }
+
+    pub fn is_guess<const LEN: usize>(&self, word: &Word<LEN>) -> bool {
+        self.guesses.contains(&word.poly_word())
+    }
+
+    pub fn is_answer<const LEN: usize>(&self, word: &Word<LEN>) -> bool {
+        self.answers.contains(&word.poly_word())
+    }
+}
+
+impl Default for Dictionary {
+    // The bundled lists: every valid guess from `dict.json`, and the common words of
+    // `answers.json` for the answers.
+    fn default() -> Self {
+        Self {
+            guesses: get_dict().iter().map(|word| *word as u64).collect(),
+            answers: get_answers().iter().map(|word| *word as u64).collect(),
+        }
+    }
+}
+
 /// A lookup table of values from dictionary.
 #[derive(Debug, Clone)]
 pub(super) struct DictTableConfig<F: FieldExt> {
@@ -38,23 +85,23 @@ impl<F: FieldExt> DictTableConfig<F> {
         }
     }
 
-    pub(super) fn load(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
-        let mut words = get_dict();
-        // println!("words {:?}", words);
+    // Zero is always in the table, as that is what a lookup sees on rows without its selector.
+    // So a selected zero passes the lookup too: callers that need a real word rule it out
+    // with a gate of their own.
+    pub(super) fn load(&self, layouter: &mut impl Layouter<F>, words: &[u64]) -> Result<(), Error> {
+        let mut words = words.to_vec();
         words.push(0);
 
         layouter.assign_table(
             || "load dictionary-check table",
             |mut table| {
-                let mut offset = 0;
-                for word in words.iter() {
+                for (offset, word) in words.iter().enumerate() {
                     table.assign_cell(
                         || "num_bits",
                         self.value,
                         offset,
-                        || Value::known(F::from(word.clone() as u64)),
+                        || Value::known(F::from(*word)),
                     )?;
-                    offset += 1;
                 }
 
                 Ok(())
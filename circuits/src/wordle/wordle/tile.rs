@@ -0,0 +1,113 @@
+use halo2_proofs::pasta::Fp;
+use serde::{Deserialize, Serialize};
+
+use super::utils::*;
+use super::WordleError;
+
+/// The color of one grid cell. Serializes as `"gray"`, `"yellow"` or `"green"`; in the circuit
+/// it's the field element GRAY, YELLOW or GREEN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tile {
+    Gray,
+    Yellow,
+    Green,
+}
+
+impl From<Tile> for u64 {
+    fn from(tile: Tile) -> u64 {
+        match tile {
+            Tile::Gray => GRAY,
+            Tile::Yellow => YELLOW,
+            Tile::Green => GREEN,
+        }
+    }
+}
+
+impl From<Tile> for Fp {
+    fn from(tile: Tile) -> Fp {
+        Fp::from(u64::from(tile))
+    }
+}
+
+impl TryFrom<u64> for Tile {
+    type Error = WordleError;
+
+    fn try_from(color: u64) -> Result<Self, WordleError> {
+        match color {
+            GRAY => Ok(Tile::Gray),
+            YELLOW => Ok(Tile::Yellow),
+            GREEN => Ok(Tile::Green),
+            _ => Err(WordleError::InvalidInput(format!("{} is not a color", color))),
+        }
+    }
+}
+
+impl TryFrom<Fp> for Tile {
+    type Error = WordleError;
+
+    fn try_from(color: Fp) -> Result<Self, WordleError> {
+        [Tile::Gray, Tile::Yellow, Tile::Green]
+            .into_iter()
+            .find(|tile| Fp::from(*tile) == color)
+            .ok_or_else(|| WordleError::InvalidInput(format!("{:?} is not a color", color)))
+    }
+}
+
+/// The tiles of each guess played, one row per guess, so the number of rows is the guess
+/// count. Serializes as a list of rows of tiles.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Grid {
+    pub rows: Vec<Vec<Tile>>,
+}
+
+impl Grid {
+    pub fn guess_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    // The colors column of a `WordleCircuit` with this grid: GUESSES rows of LEN, row-major,
+    // with the rows past the guess count GRAY.
+    pub fn to_colors<const LEN: usize, const GUESSES: usize>(&self) -> Result<Vec<Fp>, WordleError> {
+        if self.rows.len() > GUESSES {
+            return Err(WordleError::InvalidInput(format!("{} rows in a grid of {}", self.rows.len(), GUESSES)));
+        }
+
+        let mut colors = vec![Fp::from(Tile::Gray); GUESSES * LEN];
+        for (idx, row) in self.rows.iter().enumerate() {
+            if row.len() != LEN {
+                return Err(WordleError::InvalidInput(format!("row {:?} isn't {} tiles", row, LEN)));
+            }
+            for (i, tile) in row.iter().enumerate() {
+                colors[idx * LEN + i] = Fp::from(*tile);
+            }
+        }
+        Ok(colors)
+    }
+
+    // The inverse of `to_colors`, given the guess count the circuit pairs the colors with.
+    pub fn from_colors<const LEN: usize, const GUESSES: usize>(colors: &[Fp], guess_count: usize) -> Result<Self, WordleError> {
+        if guess_count > GUESSES || colors.len() != GUESSES * LEN {
+            return Err(WordleError::InvalidInput(format!("{} colors for {} guesses", colors.len(), guess_count)));
+        }
+
+        let tiles = colors
+            .iter()
+            .map(|color| Tile::try_from(*color))
+            .collect::<Result<Vec<_>, _>>()?;
+        if tiles[guess_count * LEN..].iter().any(|tile| *tile != Tile::Gray) {
+            return Err(WordleError::InvalidInput(String::from("colors set past the guess count")));
+        }
+
+        Ok(Self {
+            rows: tiles[..guess_count * LEN].chunks(LEN).map(|row| row.to_vec()).collect(),
+        })
+    }
+}
+
+impl From<Vec<Vec<Tile>>> for Grid {
+    fn from(rows: Vec<Vec<Tile>>) -> Self {
+        Self { rows }
+    }
+}
@@ -1,76 +1,219 @@
-use halo2_proofs::pasta::Fp;
+use halo2_gadgets::poseidon::primitives::{self as poseidon, ConstantLength, P128Pow5T3};
+use halo2_proofs::pasta::{group::ff::PrimeField, Fp};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+use super::{Tile, Word, WordleError};
 
 pub const BASE: u64 = 29;
+// The classic game. Circuits are generic over both, see `WordleCircuit`.
 pub const WORD_COUNT: usize = 6;
 pub const WORD_LEN : usize = 5;
+// Letters are encoded as 1 to ALPHABET_SIZE, leaving 0 for unused rows.
+pub const ALPHABET_SIZE: u64 = 26;
+// Longer words would overflow the u64 poly word, so they're cut off well before that.
+pub const MAX_WORD_LEN: usize = 12;
+
+// Color of a grid cell, as exposed in the public inputs.
+pub const GRAY: u64 = 0;
+pub const YELLOW: u64 = 1;
+pub const GREEN: u64 = 2;
+
+// Any non-empty run of at most MAX_WORD_LEN lowercase letters; checking the length is up to
+// the caller.
+pub fn word_to_chars(word: &str) -> Result<Vec<u64>, WordleError> {
+    if word.is_empty() || word.len() > MAX_WORD_LEN || !word.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(WordleError::InvalidWord(String::from(word)));
+    }
 
-pub fn word_to_chars(word: &str) -> Vec<u64> {
     let mut res = vec![];
     for c in word.chars() {
         res.push((c as u64) - ('a' as u64) + 1);
     }
-    res
+    Ok(res)
 }
 
-pub fn word_to_polyhash(word: &str) -> u64 {
-    let chars = word_to_chars(word);
+pub fn word_to_polyhash(word: &str) -> Result<u64, WordleError> {
+    let chars = word_to_chars(word)?;
     let mut hash = 0;
     for c in chars {
-        hash = hash * BASE;
+        hash *= BASE;
         hash += c;
     }
 
-    hash
+    Ok(hash)
 }
 
-pub fn compute_diff(word: &str, final_word: &str) -> Vec<Vec<Fp>> {
-    let mut res = vec![];
-    let mut green = vec![];
-    for i in 0..WORD_LEN {
-        if word.chars().nth(i) == final_word.chars().nth(i) {
-            green.push(Fp::one());
-        } else {
-            green.push(Fp::zero());
+pub fn polyhash_to_word(mut hash: u64) -> Result<String, WordleError> {
+    let mut chars = vec![];
+    while hash > 0 {
+        let c = hash % BASE;
+        if c == 0 || c > ALPHABET_SIZE {
+            return Err(WordleError::InvalidInput(format!("{} is not a poly word", hash)));
         }
+        chars.push((c as u8 - 1 + b'a') as char);
+        hash /= BASE;
+    }
+    Ok(chars.iter().rev().collect())
+}
+
+// What the puzzle host publishes in place of the day's final word. The salt keeps the
+// commitment from being brute forced over the dictionary.
+pub fn compute_commitment<const LEN: usize>(final_word: &Word<LEN>, salt: Fp, day: u64) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<3>, 3, 2>::init()
+        .hash([Fp::from(final_word.poly_word()), salt, Fp::from(day)])
+}
+
+// A player's handle as a field element, for use as their id. It has to fit in 31 bytes.
+pub fn handle_to_field(handle: &str) -> Result<Fp, WordleError> {
+    if handle.len() >= 32 {
+        return Err(WordleError::InvalidInput(format!("handle {:?} is too long", handle)));
+    }
+    let mut repr = [0u8; 32];
+    repr[..handle.len()].copy_from_slice(handle.as_bytes());
+    // below 2^248, so always canonical
+    Ok(Fp::from_repr(repr).unwrap())
+}
+
+// Public tag a proof is bound to, Poseidon(id), where the id is a handle or a signing key's
+// `key_id`. Anyone who knows the player can recompute it.
+pub fn compute_player_tag(id: Fp) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<1>, 3, 2>::init().hash([id])
+}
+
+// Poseidon(id, secret), which a player registers once for their id with whoever checks their
+// proofs. It pins the secret behind their nullifiers, so a fresh secret won't verify.
+pub fn compute_registration(id: Fp, secret: Fp) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([id, secret])
+}
+
+// Poseidon(secret, day), the same for every proof a player makes for a day, so a verifier
+// can reject a second submission.
+pub fn compute_nullifier(secret: Fp, day: u64) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init().hash([secret, Fp::from(day)])
+}
+
+// The grid as one field element: the colors of every row, unused ones gray, read as base 3
+// digits with the first word first.
+pub fn pack_grid(rows: &[Vec<Tile>]) -> Fp {
+    rows
+        .iter()
+        .flatten()
+        .fold(Fp::zero(), |acc, tile| acc * Fp::from(GREEN + 1) + Fp::from(*tile))
+}
+
+// What a player signs for SignedWordleCircuit, Poseidon(day, packed grid).
+pub fn compute_result_message(day: u64, rows: &[Vec<Tile>]) -> Fp {
+    poseidon::Hash::<_, P128Pow5T3, ConstantLength<2>, 3, 2>::init()
+        .hash([Fp::from(day), pack_grid(rows)])
+}
+
+// Field elements cross into JSON as their 32 byte little-endian representation, always
+// through here: `#[serde(with = "fp_repr")]` on a field, or `FpRepr` for a loose value.
+pub mod fp_repr {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &Fp, serializer: S) -> Result<S::Ok, S::Error> {
+        value.to_repr().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Fp, D::Error> {
+        let repr = <[u8; 32]>::deserialize(deserializer)?;
+        Option::from(Fp::from_repr(repr)).ok_or_else(|| D::Error::custom("not a canonical field element"))
     }
-    res.push(green);
-
-    let mut yellow = vec![Fp::zero(); WORD_LEN];
-    for i in 0..WORD_LEN {
-        for j in 0..WORD_LEN {
-            if word.chars().nth(i) == final_word.chars().nth(j) {
-                yellow[i] = Fp::one();
-            }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FpRepr(#[serde(with = "fp_repr")] pub Fp);
+
+/// The per-day commitments a host publishes ahead of time. Verifiers look up the day a
+/// proof claims here, so a grid only verifies against that day's puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentSchedule {
+    commitments: BTreeMap<u64, FpRepr>,
+}
+
+impl CommitmentSchedule {
+    pub fn insert(&mut self, day: u64, commitment: Fp) {
+        self.commitments.insert(day, FpRepr(commitment));
+    }
+
+    pub fn get(&self, day: u64) -> Option<Fp> {
+        self.commitments.get(&day).map(|commitment| commitment.0)
+    }
+}
+
+/// The registration on file for each player tag, see `compute_registration`. A verifier
+/// records it with a player's first accepted proof and holds their later proofs to it, so a
+/// fresh secret can't buy a fresh nullifier.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Registrations {
+    registrations: Vec<(FpRepr, FpRepr)>,
+}
+
+impl Registrations {
+    pub fn get(&self, player_tag: Fp) -> Option<Fp> {
+        self.registrations
+            .iter()
+            .find(|(tag, _)| tag.0 == player_tag)
+            .map(|(_, registration)| registration.0)
+    }
+
+    // Whether `registration` is the one on file for `player_tag`, or there is none yet.
+    pub fn matches(&self, player_tag: Fp, registration: Fp) -> bool {
+        self.get(player_tag).unwrap_or(registration) == registration
+    }
+
+    // Records the first registration seen for `player_tag`; a later one doesn't replace it.
+    pub fn insert(&mut self, player_tag: Fp, registration: Fp) {
+        if self.get(player_tag).is_none() {
+            self.registrations.push((FpRepr(player_tag), FpRepr(registration)));
         }
     }
-    res.push(yellow);
-    // println!("word {:?} final {:?} res {:?}", word, final_word, res);
-    
-    res
 }
 
-pub fn compute_diff_u64(word: &str, final_word: &str) -> Vec<Vec<u64>> {
-    let mut res = vec![];
-    let mut green = vec![];
-    for i in 0..WORD_LEN {
-        if word.chars().nth(i) == final_word.chars().nth(i) {
-            green.push(1 as u64);
-        } else {
-            green.push(0 as u64);
+// Wordle colouring rules: greens consume their letter first, then yellows are
+// handed out left to right while unmatched copies of the letter remain.
+pub fn compute_diff<const LEN: usize>(word: &Word<LEN>, final_word: &Word<LEN>) -> Vec<Tile> {
+    let chars = word.chars();
+    let final_chars = final_word.chars();
+
+    let len = chars.len();
+    let mut res = vec![Tile::Gray; len];
+    for i in 0..len {
+        if chars[i] == final_chars[i] {
+            res[i] = Tile::Green;
         }
     }
-    res.push(green);
-
-    let mut yellow = vec![0 as u64; WORD_LEN];
-    for i in 0..WORD_LEN {
-        for j in 0..WORD_LEN {
-            if word.chars().nth(i) == final_word.chars().nth(j) {
-                yellow[i] = 1 as u64;
-            }
+
+    for i in 0..len {
+        if res[i] == Tile::Green {
+            continue;
+        }
+        let available = (0..len)
+            .filter(|&j| res[j] != Tile::Green && final_chars[j] == chars[i])
+            .count();
+        let used = (0..i)
+            .filter(|&k| res[k] == Tile::Yellow && chars[k] == chars[i])
+            .count();
+        if used < available {
+            res[i] = Tile::Yellow;
         }
     }
-    res.push(yellow);
-    // println!("word {:?} final {:?} res {:?}", word, final_word, res);
-    
+
     res
-}
\ No newline at end of file
+}
+
+// Hard mode: greens of the previous guess must stay in place and its yellow letters
+// must be used somewhere in the next guess. `prev_diff` is the colors of `prev_word`.
+pub fn satisfies_hard_mode<const LEN: usize>(prev_word: &Word<LEN>, prev_diff: &[Tile], word: &Word<LEN>) -> bool {
+    let prev_chars = prev_word.chars();
+    let chars = word.chars();
+
+    prev_diff.iter().zip(prev_chars.iter()).enumerate().all(|(i, (tile, prev_char))| match tile {
+        Tile::Green => chars[i] == *prev_char,
+        Tile::Yellow => chars.contains(prev_char),
+        Tile::Gray => true,
+    })
+}
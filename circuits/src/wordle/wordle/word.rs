@@ -0,0 +1,76 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::dict::*;
+use super::utils::*;
+use super::WordleError;
+
+/// A guess or solution of LEN letters, lowercased. Anything else is turned away by
+/// `Word::new`, so the letter codes and poly word of a `Word` are always in range.
+/// Serializes as the string, and deserializing goes through `Word::new`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Word<const LEN: usize = WORD_LEN>(String);
+
+impl<const LEN: usize> Word<LEN> {
+    // Surrounding whitespace and case are ignored; digits, punctuation and non-ASCII
+    // letters are not. No word fits a LEN above MAX_WORD_LEN, whose poly words overflow.
+    pub fn new(word: &str) -> Result<Self, WordleError> {
+        let normalized = word.trim().to_ascii_lowercase();
+        if LEN > MAX_WORD_LEN || normalized.len() != LEN || !normalized.bytes().all(|c| c.is_ascii_lowercase()) {
+            return Err(WordleError::InvalidWord(String::from(word)));
+        }
+        Ok(Self(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    // 1 to ALPHABET_SIZE per letter, like `word_to_chars`.
+    pub fn chars(&self) -> Vec<u64> {
+        self.0.bytes().map(|c| (c - b'a' + 1) as u64).collect()
+    }
+
+    pub fn poly_word(&self) -> u64 {
+        self.chars().iter().fold(0, |hash, c| hash * BASE + c)
+    }
+
+    /// Whether the word is in the bundled dictionary, which is what `Dictionary::default`
+    /// and so `WordleCircuit` check guesses against. Solutions come from the shorter answer
+    /// list, see `Dictionary::is_answer`.
+    pub fn is_in_dictionary(&self) -> bool {
+        let poly_word = self.poly_word();
+        get_dict().iter().any(|word| *word as u64 == poly_word)
+    }
+}
+
+impl<const LEN: usize> FromStr for Word<LEN> {
+    type Err = WordleError;
+
+    fn from_str(word: &str) -> Result<Self, WordleError> {
+        Self::new(word)
+    }
+}
+
+impl<const LEN: usize> TryFrom<String> for Word<LEN> {
+    type Error = WordleError;
+
+    fn try_from(word: String) -> Result<Self, WordleError> {
+        Self::new(&word)
+    }
+}
+
+impl<const LEN: usize> From<Word<LEN>> for String {
+    fn from(word: Word<LEN>) -> String {
+        word.0
+    }
+}
+
+impl<const LEN: usize> fmt::Display for Word<LEN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
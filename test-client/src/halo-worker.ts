@@ -1,12 +1,23 @@
 import { expose } from 'comlink';
 
+// 32 byte little-endian salt the host committed to "fluff" with
+const SALT = [0x17, 0x5a, ...new Array(30).fill(0)];
+const DAY = 1;
+const PLAYER = "alice";
+// 32 byte little-endian secret the player's tag and nullifiers are derived from
+const PLAYER_SECRET = [0xc7, 0x5e, ...new Array(30).fill(0)];
+// nullifiers of the proofs accepted so far
+const seen_nullifiers = new Set<string>();
+// [player tag, registration] of every player accepted so far, see `Registrations`
+const registrations: { registrations: [number[], number[]][] } = { registrations: [] };
+
 async function get_play_diff() {
     console.log('diffing');
     const multiThread = await import('halowordle');
     await multiThread.default();
     await multiThread.initThreadPool(navigator.hardwareConcurrency);
     multiThread.init_panic_hook();
-    const ret = multiThread.get_play_diff("fluff", ["fluff", "fluff", "fluff", "fluff", "fluff", "fluff"]);
+    const ret = multiThread.get_play_diff("fluff", ["audio", "hunky", "funky", "fluff"]);
     return ret;
 }
 
@@ -29,7 +40,7 @@ async function prove_play() {
     await multiThread.default();
     await multiThread.initThreadPool(navigator.hardwareConcurrency);
     console.log('here we go');
-    const ret = multiThread.prove_play("fluff", ["fluff", "fluff", "fluff", "fluff", "fluff", "fluff"], params);
+    const ret = multiThread.prove_play("fluff", SALT, DAY, true, PLAYER, PLAYER_SECRET, ["audio", "hunky", "funky", "fluff"], params);
     return ret;
 }
 
@@ -45,7 +56,33 @@ async function verify_play(proof: any, diffs_js: any) {
     await multiThread.default();
     await multiThread.initThreadPool(navigator.hardwareConcurrency);
     console.log('here we go');
-    const ret = multiThread.verify_play("fluff", proof, diffs_js, params);
+    // the host publishes one commitment per day
+    const commitment = multiThread.get_commitment("fluff", SALT, DAY);
+    const schedule = { commitments: { [DAY]: commitment } };
+    const player_tag = multiThread.get_player_tag(PLAYER);
+    const nullifier = multiThread.get_nullifier(PLAYER_SECRET, DAY);
+    // what the player claims; verify_play holds the registration to the one on file
+    const public_inputs = {
+        commitment,
+        day: DAY,
+        hard_mode: true,
+        grid: diffs_js,
+        player_tag,
+        registration: multiThread.get_registration(PLAYER, PLAYER_SECRET),
+        nullifier,
+    };
+    // a second proof for the day carries the same nullifier
+    if (seen_nullifiers.has(nullifier.join(","))) {
+        return false;
+    }
+    const ret = multiThread.verify_play(schedule, registrations, public_inputs, proof, params);
+    if (ret) {
+        seen_nullifiers.add(nullifier.join(","));
+        const registered = registrations.registrations.some(([tag]) => tag.join(",") === player_tag.join(","));
+        if (!registered) {
+            registrations.registrations.push([player_tag, public_inputs.registration]);
+        }
+    }
     return ret;
 }
 